@@ -1,4 +1,6 @@
 use soroban_sdk::contracterror;
+use workshop_common::math::MathError;
+use workshop_common::timelock::TimelockError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -17,5 +19,50 @@ pub enum DeFindexError {
     /// Invalid parameters
     InvalidArgument = 6,
 
-    NegativeNotAllowed= 7
+    NegativeNotAllowed= 7,
+    /// Swap amount is too large relative to the pair reserves
+    InsufficientLiquidity = 8,
+    /// Arithmetic overflow
+    ArithmeticError = 9,
+    /// Timelocked admin operation was not scheduled
+    OperationNotScheduled = 10,
+    /// Timelocked admin operation was scheduled but its delay has not elapsed yet
+    TimelockNotExpired = 11,
+    /// Vault minted fewer shares than the caller's minimum
+    InsufficientShares = 12,
+    /// The router swap of a zap failed
+    SwapStepFailed = 13,
+    /// The vault deposit of a zap failed
+    DepositStepFailed = 14,
+    /// Token transfer delivered a different amount than requested
+    TransferMismatch = 15,
+    /// Zap deadline has already passed
+    DeadlineExpired = 16,
+    /// Deposit amount is above the caller's `max_amount_in`
+    AmountExceedsMax = 17,
+    /// Zap-out returned less `token_out` than the caller's minimum
+    InsufficientOutput = 18,
+    /// Caller's balance is lower than the amount to deposit
+    InsufficientBalance = 19,
+    /// Investing the vault's idle funds failed (no active strategy or the vault rejected it)
+    InvestFailed = 20,
+}
+
+impl From<MathError> for DeFindexError {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::DivisionByZero => DeFindexError::InvalidArgument,
+            MathError::Overflow => DeFindexError::ArithmeticError,
+        }
+    }
+}
+
+impl From<TimelockError> for DeFindexError {
+    fn from(err: TimelockError) -> Self {
+        match err {
+            TimelockError::NotScheduled => DeFindexError::OperationNotScheduled,
+            TimelockError::NotExpired => DeFindexError::TimelockNotExpired,
+            TimelockError::TooEarly | TimelockError::DelayTooLong => DeFindexError::InvalidArgument,
+        }
+    }
 }
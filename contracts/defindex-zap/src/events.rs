@@ -0,0 +1,34 @@
+use soroban_sdk::{contractevent, Address};
+
+use workshop_common::models::SwapReceipt;
+
+/// Emitted when the admin points the zapper at a new underlying asset
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnderlyingAssetUpdated {
+    pub old_underlying: Address,
+    pub new_underlying: Address,
+}
+
+/// Emitted when an investing deposit failed and the funds were deposited as idle instead
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvestFallback {
+    pub depositor: Address,
+    pub amount: i128,
+}
+
+/// Emitted when idle funds could not be invested (no active strategy or the vault rejected the
+/// rebalance); the triggering deposit still succeeds
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvestSkipped {
+    pub idle_amount: i128,
+}
+
+/// Emitted for the swap leg of every zap
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapExecuted {
+    pub receipt: SwapReceipt,
+}
@@ -6,40 +6,78 @@
 //! vault's underlying asset first.
 //!
 //! ## Key Characteristics:
-//! - Contract acts as a coordinator - never holds user tokens
+//! - Contract acts as a coordinator - the default zaps never hold user tokens
 //! - Combines swap + deposit in a single transaction (improved UX)
 //! - User authorizes both operations through one signature
-//! - No additional authorization context needed (simple proxy pattern)
 //! - Token flow: User → Router (swap) → User → Vault (deposit)
+//! - `deposit_custodial` is the exception: it takes custody and builds the auth context itself
+//!   (see below)
 //!
 //! ## Example Use Case:
 //! - User has EURC but wants to deposit into an XLM vault
 //! - Without zapper: User must manually swap EURC → XLM, then deposit XLM
 //! - With zapper: User calls `deposit(EURC, amount)` and everything happens atomically
 //!
-//! ## Why No Authorization is Needed:
-//! The user's signature (`caller.require_auth()`) authorizes this contract to coordinate both
-//! the swap (via Soroswap Router) and the deposit (via DeFindex Vault) on their behalf. Since
-//! the contract never takes custody of tokens, no additional authorization context is required.
+//! ## Authorization:
+//! In the default zaps the user's signature (`caller.require_auth()`) authorizes this contract
+//! to coordinate both the swap (via Soroswap Router) and the deposit (via DeFindex Vault) on
+//! their behalf. The router and the vault pull tokens from the user's account, so no
+//! additional authorization context is required.
+//!
+//! `deposit_custodial` serves callers whose signature only covers this contract (e.g. another
+//! contract or a smart wallet). It pulls `token_in` into the zapper, swaps and deposits as
+//! itself, and pre-authorizes the router's and the vault's token pulls with
+//! `authorize_as_current_contract`, like `soroswap-auth`. The shares are then forwarded to the
+//! user.
 
 use soroban_sdk::{
-    Address, Env, Vec, contract, contractimpl, vec
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec, auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation}, contract, contractimpl, token, vec
 };
 
+// The generated client exceeds clippy's argument limit in test builds
+#[allow(clippy::too_many_arguments)]
 mod defindex_vault;
+mod events;
+mod models;
+// The generated client exceeds clippy's argument limit in test builds
+#[allow(clippy::too_many_arguments)]
 mod soroswap_router;
+mod staking;
 mod storage;
 mod error;
+#[cfg(test)]
+mod test;
 
-use defindex_vault::DeFindexVaultClient;
+use defindex_vault::{DeFindexVaultClient, Instruction};
+use events::{InvestFallback, InvestSkipped, SwapExecuted, UnderlyingAssetUpdated};
+pub use models::{ZapConfig, ZapSimulation};
+pub use workshop_common::models::SwapReceipt;
 use soroswap_router::SoroswapRouterClient;
+use staking::StakingClient;
 use storage::{
-    extend_instance_ttl, get_vault_address, set_vault_address, get_soroswap_router_address, set_soroswap_router_address
+    extend_instance_ttl, get_vault_address, set_vault_address, get_soroswap_router_address, set_soroswap_router_address,
+    get_admin, set_admin, is_staking_contract_allowed, set_staking_contract_allowed,
+    is_vault_allowed, set_vault_allowed, get_max_path_len, set_max_path_len,
+    get_max_reserve, set_max_reserve, get_denominator, set_denominator, get_auto_invest_threshold, set_auto_invest_threshold,
 };
+use workshop_common::math::{mul_div, Rounding};
+use workshop_common::path::{select_best, SwapPath};
+use workshop_common::time::{ensure_not_expired, resolve_deadline};
+use workshop_common::timelock::{self, check_timelock, op_hash};
+use workshop_common::token::safe_transfer;
 use error::DeFindexError;
 
 use crate::storage::{get_underlying_asset_address, set_underlying_asset_address};
 
+/// Basis points denominator (10_000 bps = 100%)
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Parts-per-million denominator (1_000_000 ppm = 100%), for sub-basis-point granularity
+pub const PPM_DENOMINATOR: i128 = 1_000_000;
+
+/// Fixed-point scale for prices (7 decimals, like Stellar assets)
+pub const PRICE_SCALE: i128 = 10_000_000;
+
 /// Validates that the amount is non-negative
 ///
 /// Prevents arithmetic issues and invalid swap amounts
@@ -51,21 +89,309 @@ pub fn check_nonnegative_amount(amount: i128) -> Result<(), DeFindexError> {
     }
 }
 
+/// Returns the address of the native XLM Stellar Asset Contract on the current network
+///
+/// Derived from the XDR of `Asset::Native`, so no address needs to be configured per network.
+fn native_asset_address(e: &Env) -> Address {
+    e.deployer()
+        .with_stellar_asset(Bytes::from_array(e, &[0, 0, 0, 0]))
+        .deployed_address()
+}
+
+/// Verifies that `admin` signed the transaction and is the stored admin
+fn check_admin(e: &Env, admin: &Address) -> Result<(), DeFindexError> {
+    admin.require_auth();
+    if *admin != get_admin(e) {
+        return Err(DeFindexError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Reverts with `InsufficientLiquidity` if any hop of the swap would consume more than
+/// `max_reserve` (in units of the configured denominator) of that pair's input reserve
+///
+/// Swapping a large amount against an illiquid pair produces a terrible rate for the user,
+/// so the admin can cap the price impact. Disabled when `max_reserve` is 0.
+fn check_liquidity(e: &Env, router: &SoroswapRouterClient, path: &SwapPath, amount: i128) -> Result<(), DeFindexError> {
+    let max_reserve = get_max_reserve(e);
+    if max_reserve == 0 {
+        return Ok(());
+    }
+
+    let factory = router.get_factory();
+    let path = path.as_vec();
+    // Expected input amount of each hop: amounts[i] goes into the pair (path[i], path[i + 1])
+    let amounts = router.router_get_amounts_out(&amount, path);
+
+    for i in 0..path.len() - 1 {
+        let (reserve_in, _) = router.get_reserves(&factory, &path.get_unchecked(i), &path.get_unchecked(i + 1));
+        let max_amount_in = mul_div(e, reserve_in, max_reserve as i128, get_denominator(e), Rounding::Down)?;
+        if amounts.get_unchecked(i) > max_amount_in {
+            return Err(DeFindexError::InsufficientLiquidity);
+        }
+    }
+    Ok(())
+}
+
+/// Estimates the vault shares minted for depositing `amount` of the underlying asset
+///
+/// Mirrors the vault's pro-rata share math: `amount * total_supply / total_managed_funds`.
+/// The first deposit into an empty vault mints shares 1:1.
+fn estimate_shares(e: &Env, vault: &DeFindexVaultClient, underlying_asset: &Address, amount: i128) -> Result<i128, DeFindexError> {
+    let total_supply = vault.total_supply();
+    let total_managed = vault
+        .fetch_total_managed_funds()
+        .iter()
+        .find(|allocation| allocation.asset == *underlying_asset)
+        .map(|allocation| allocation.total_amount)
+        .unwrap_or(0);
+
+    if total_supply == 0 || total_managed == 0 {
+        return Ok(amount);
+    }
+    Ok(mul_div(e, amount, total_supply, total_managed, Rounding::Down)?)
+}
+
+/// Swaps `amount` along `path` through the router, paying out to `to`, and emits `SwapExecuted`
+///
+/// A router failure surfaces as `SwapStepFailed` so it can't be mistaken for a vault error.
+/// No minimum output is set here: callers enforce slippage on what they do with the output.
+///
+/// ## Returns:
+/// Amount of the last token in `path` received by `to`
+fn swap_step(e: &Env, router: &SoroswapRouterClient, path: &SwapPath, amount: i128, to: &Address, deadline: u64) -> Result<i128, DeFindexError> {
+    let swap_result = match router.try_swap_exact_tokens_for_tokens(
+        &amount,       // Exact amount of the first token to swap
+        &0,            // Minimum amount out (enforced by the caller)
+        path.as_vec(), // Swap route: token_in → ... → token_out
+        to,            // Recipient of the swapped tokens
+        &deadline,     // Deadline (caller-provided or now + DEFAULT_DEADLINE_WINDOW)
+    ) {
+        Ok(Ok(swap_result)) => swap_result,
+        _ => return Err(DeFindexError::SwapStepFailed),
+    };
+
+    let amount_out = swap_result.last().unwrap();
+    SwapExecuted {
+        receipt: SwapReceipt::new(path.as_vec(), amount, amount_out),
+    }
+    .publish(e);
+
+    Ok(amount_out)
+}
+
+/// Deposits `amount` of the underlying asset from `from` into `vault`, keeping it idle
+///
+/// A vault failure surfaces as `DepositStepFailed`. Slippage is enforced by the caller on
+/// the returned shares.
+///
+/// ## Returns:
+/// Amount of vault shares minted to `from`
+fn deposit_step(e: &Env, vault: &DeFindexVaultClient, amount: i128, from: &Address) -> Result<i128, DeFindexError> {
+    match vault.try_deposit(
+        &vec![e, amount], // Amounts array (for multi-asset vaults; currently single-asset)
+        &vec![e, 0],      // Minimum amounts out (slippage is enforced through the shares)
+        from,             // Depositor (receives vault shares)
+        &false,           // invest: false = keep as idle in vault; true = invest into strategy
+    ) {
+        Ok(Ok((_, shares_minted, _))) => Ok(shares_minted),
+        _ => Err(DeFindexError::DepositStepFailed),
+    }
+}
+
+/// Swaps along `path` and deposits the output into the vault, on behalf of `caller`
+///
+/// Shared by every zap entry point. Authorization must already be checked by the caller.
+/// Reverts with `InsufficientShares` if the vault mints fewer than `min_shares`, independently
+/// of whether the vault enforces its own minimum.
+///
+/// ## Returns:
+/// `(underlying_deposited, shares_minted)`
+fn swap_and_deposit(e: &Env, caller: &Address, path: &SwapPath, amount: i128, min_shares: i128, deadline: u64) -> Result<(i128, i128), DeFindexError> {
+    ensure_not_expired(e, deadline, DeFindexError::DeadlineExpired)?;
+
+    // Step 1: Swap along the path via Soroswap Router
+    let soroswap_router_address = get_soroswap_router_address(e);
+    let soroswap_router_client = SoroswapRouterClient::new(e, &soroswap_router_address);
+
+    // Protect the user from swapping into an illiquid pool
+    check_liquidity(e, &soroswap_router_client, path, amount)?;
+
+    // Execute swap - tokens go from user → pair(s) → back to user (as underlying_asset)
+    // User's signature authorizes the router to transfer token_in from their account
+    let total_swapped_amount = swap_step(e, &soroswap_router_client, path, amount, caller, deadline)?;
+
+    // Step 2: Deposit the swapped underlying_asset into DeFindex vault
+    // User's signature authorizes vault to transfer from their account
+    let defindex_vault_client = DeFindexVaultClient::new(e, &get_vault_address(e));
+    let shares_minted = deposit_step(e, &defindex_vault_client, total_swapped_amount, caller)?;
+
+    if shares_minted < min_shares {
+        return Err(DeFindexError::InsufficientShares);
+    }
+
+    Ok((total_swapped_amount, shares_minted))
+}
+
+/// Pre-authorizes a `token.transfer(this contract, to, amount)` made by the next contract called
+///
+/// Needed whenever this contract holds the funds and a sub-invocation (the router pulling into a
+/// pair, the vault pulling a deposit) moves them, since the runtime only auto-authorizes the
+/// direct invoker. Same pattern as `soroswap-auth`.
+fn authorize_transfer(e: &Env, token: &Address, to: &Address, amount: i128) {
+    let transfer_args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e), // From: this contract
+        to.into_val(e),                            // To: pair or vault
+        amount.into_val(e),                        // Exact amount allowed
+    ];
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: transfer_args,
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+}
+
+/// Invests the vault's idle underlying asset into its first active strategy, if it is at
+/// least `min_idle`
+///
+/// This contract must be the vault's rebalance manager. Fails with `InvestFailed` if the vault
+/// can't report its funds, every strategy is paused or the vault rejects the rebalance; in the
+/// last two cases `InvestSkipped` is emitted first (and rolled back if the error propagates).
+///
+/// ## Returns:
+/// Amount of underlying asset invested (0 if nothing was idle or it was below `min_idle`)
+fn invest_idle_above(e: &Env, min_idle: i128) -> Result<i128, DeFindexError> {
+    let underlying_asset = get_underlying_asset_address(e);
+    let defindex_vault_client = DeFindexVaultClient::new(e, &get_vault_address(e));
+
+    let Ok(Ok(allocations)) = defindex_vault_client.try_fetch_total_managed_funds() else {
+        return Err(DeFindexError::InvestFailed);
+    };
+    let Some(allocation) = allocations.iter().find(|allocation| allocation.asset == underlying_asset) else {
+        return Ok(0);
+    };
+    if allocation.idle_amount == 0 || allocation.idle_amount < min_idle {
+        return Ok(0);
+    }
+
+    let Some(strategy) = allocation.strategy_allocations.iter().find(|strategy| !strategy.paused) else {
+        InvestSkipped { idle_amount: allocation.idle_amount }.publish(e);
+        return Err(DeFindexError::InvestFailed);
+    };
+
+    // The vault checks `caller.require_auth()`; as the direct invoker this contract passes it
+    let invested = defindex_vault_client.try_rebalance(
+        &e.current_contract_address(),
+        &vec![e, Instruction::Invest(strategy.strategy_address, allocation.idle_amount)],
+    );
+    if !matches!(invested, Ok(Ok(_))) {
+        InvestSkipped { idle_amount: allocation.idle_amount }.publish(e);
+        return Err(DeFindexError::InvestFailed);
+    }
+
+    Ok(allocation.idle_amount)
+}
+
+/// Invests the vault's idle funds if they crossed the admin-set auto-invest threshold
+///
+/// Best effort: runs inside user deposits, so a failed invest is skipped (see `InvestSkipped`)
+/// and never blocks the deposit.
+fn auto_invest_idle(e: &Env) {
+    let auto_invest_threshold = get_auto_invest_threshold(e);
+    if auto_invest_threshold > 0 {
+        let _ = invest_idle_above(e, auto_invest_threshold);
+    }
+}
+
+/// Deposits `amount` of the underlying asset from `caller` straight into the vault, with no swap
+///
+/// Shared by `deposit_underlying` and the XLM-vault case of `deposit_native`. With `invest`, the
+/// deposit is first attempted with the vault investing it; if the strategy rejects it, the
+/// failed call is rolled back, `InvestFallback` is emitted and the funds are deposited as idle
+/// through `deposit_step`. Authorization must already be checked by the caller.
+///
+/// ## Returns:
+/// Amount of vault shares minted to `caller`
+fn deposit_direct(e: &Env, caller: &Address, amount: i128, invest: bool, min_shares: i128) -> Result<i128, DeFindexError> {
+    let defindex_vault_client = DeFindexVaultClient::new(e, &get_vault_address(e));
+
+    let invested = if invest {
+        defindex_vault_client
+            .try_deposit(&vec![e, amount], &vec![e, 0], caller, &true)
+            .ok()
+            .and_then(|r| r.ok())
+            .map(|(_, shares_minted, _)| shares_minted)
+    } else {
+        None
+    };
+    let shares_minted = match invested {
+        Some(shares_minted) => shares_minted,
+        None => {
+            if invest {
+                InvestFallback { depositor: caller.clone(), amount }.publish(e);
+            }
+            deposit_step(e, &defindex_vault_client, amount, caller)?
+        }
+    };
+
+    if shares_minted < min_shares {
+        return Err(DeFindexError::InsufficientShares);
+    }
+
+    Ok(shares_minted)
+}
+
 #[contract]
 struct DeFindexSimple;
 
 #[contractimpl]
 impl DeFindexSimple {
-    /// Initialize the zapper contract with required addresses
+    /// Initialize the zapper contract with its configuration
     ///
     /// ## Parameters:
-    /// - `vault_address`: The DeFindex vault where deposits will be made
-    /// - `router_address`: The Soroswap router used for token swaps
-    /// - `underlying_asset`: The vault's underlying asset (target token for swaps)
-    pub fn __constructor(e: Env, vault_address: Address, router_address: Address, underlying_asset: Address) {
-        set_vault_address(&e, vault_address);
-        set_soroswap_router_address(&e, router_address);
-        set_underlying_asset_address(&e, underlying_asset);
+    /// - `config`: Admin, vault, router and underlying asset addresses, and the denominator of
+    ///   the liquidity guard (see `ZapConfig`)
+    /// - `check_underlying`: Probe `underlying_asset.decimals()` so a wrong address fails at deploy
+    ///   time instead of at the first deposit (set to `false` for test scaffolding)
+    ///
+    /// The denominator is fixed for the contract's lifetime: `BPS_DENOMINATOR` (10_000, basis
+    /// points) or `PPM_DENOMINATOR` (1_000_000, parts per million). Ppm allows sub-basis-point
+    /// guards at the cost of less readable settings.
+    pub fn __constructor(e: Env, config: ZapConfig, check_underlying: bool) {
+        let denominator = config.denominator as i128;
+        if denominator != BPS_DENOMINATOR && denominator != PPM_DENOMINATOR {
+            panic!("denominator must be 10_000 (bps) or 1_000_000 (ppm)");
+        }
+        if check_underlying {
+            let underlying_client = token::Client::new(&e, &config.underlying_asset);
+            if !matches!(underlying_client.try_decimals(), Ok(Ok(_))) {
+                panic!("underlying_asset is not a valid token contract");
+            }
+        }
+
+        set_admin(&e, config.admin);
+        set_vault_address(&e, config.vault);
+        set_soroswap_router_address(&e, config.router);
+        set_underlying_asset_address(&e, config.underlying_asset);
+        set_denominator(&e, denominator);
+    }
+
+    /// Returns the current zapper configuration
+    pub fn get_config(e: Env) -> ZapConfig {
+        ZapConfig {
+            admin: get_admin(&e),
+            vault: get_vault_address(&e),
+            router: get_soroswap_router_address(&e),
+            underlying_asset: get_underlying_asset_address(&e),
+            denominator: get_denominator(&e) as u32,
+        }
     }
 
     /// Zap: Swap any token to vault's underlying asset and deposit in one transaction
@@ -76,7 +402,9 @@ impl DeFindexSimple {
     /// 3. All happens atomically in one user signature
     ///
     /// ## Authorization Flow (Simple Proxy):
-    /// - User signs the transaction (`caller.require_auth()`)
+    /// - User signs the transaction, scoped to every argument except `caller`
+    ///   (`caller.require_auth_for_args(...)`), so whoever relays the user's auth entry can't
+    ///   change any of them (in particular the `min_shares` and `max_amount_in` guards)
     /// - User's signature authorizes:
     ///   1. Router to transfer `token_in` from user → pair (for the swap)
     ///   2. Vault to transfer `underlying_asset` from user → vault (for the deposit)
@@ -92,53 +420,689 @@ impl DeFindexSimple {
     /// - `caller`: The user depositing (must sign the transaction)
     /// - `token_in`: The token user is depositing (will be swapped to underlying asset)
     /// - `amount`: Amount of `token_in` to swap and deposit
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    /// - `compound_first`: Claim and reinvest the caller's pending vault rewards before depositing
+    ///   (ignored if the vault has no `claim` method)
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `max_amount_in`: Ceiling on the `token_in` the zap may pull, guarding against front-end bugs
     ///
     /// ## Returns:
     /// Amount of underlying asset deposited into the vault
-    pub fn deposit(e: Env, caller: Address, token_in: Address, amount: i128) -> Result<i128, DeFindexError> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit(e: Env, caller: Address, token_in: Address, amount: i128, deadline: Option<u64>, compound_first: bool, min_shares: i128, max_amount_in: i128) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction for exactly these deposit parameters
+        caller.require_auth_for_args(vec![
+            &e,
+            token_in.into_val(&e),
+            amount.into_val(&e),
+            deadline.into_val(&e),
+            compound_first.into_val(&e),
+            min_shares.into_val(&e),
+            max_amount_in.into_val(&e),
+        ]);
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        if amount > max_amount_in {
+            return Err(DeFindexError::AmountExceedsMax);
+        }
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        // Get the vault's underlying asset (the target token for our swap)
+        let underlying_asset = get_underlying_asset_address(&e);
+
+        // Build swap path (direct pair)
+        let path = SwapPath::direct(&e, &token_in, &underlying_asset, DeFindexError::InvalidArgument)?;
+
+        // Optionally compound pending rewards first. Not every vault supports it, so the
+        // call is attempted with `try_invoke_contract` and a failure is simply ignored.
+        if compound_first {
+            let _ = e.try_invoke_contract::<Val, soroban_sdk::Error>(
+                &get_vault_address(&e),
+                &Symbol::new(&e, "claim"),
+                vec![&e, caller.into_val(&e)],
+            );
+        }
+
+        let (total_swapped_amount, _) = swap_and_deposit(&e, &caller, &path, amount, min_shares, deadline)?;
+
+        // Invest the vault's idle funds once they cross the admin-set threshold
+        auto_invest_idle(&e);
+
+        // Return the amount deposited
+        Ok(total_swapped_amount)
+    }
+
+    /// Zap through a multi-hop route: swap along `path` and deposit the result into the vault
+    ///
+    /// Same flow as `deposit`, for tokens without a direct pair against the underlying asset.
+    /// The path is validated as a `SwapPath` and must end with the vault's underlying asset.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `path`: Swap route, starting with the token deposited and ending with the underlying asset
+    /// - `amount`: Amount of the first token in `path` to swap and deposit
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Amount of underlying asset deposited into the vault
+    pub fn deposit_with_path(e: Env, caller: Address, path: Vec<Address>, amount: i128, min_shares: i128, deadline: Option<u64>) -> Result<i128, DeFindexError> {
         // Verify the caller has signed this transaction
         caller.require_auth();
         check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        let path = SwapPath::new(path, get_max_path_len(&e), DeFindexError::InvalidArgument)?;
         extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        // The route must land on the vault's underlying asset
+        let underlying_asset = get_underlying_asset_address(&e);
+        if path.token_out() != underlying_asset {
+            return Err(DeFindexError::InvalidArgument);
+        }
+
+        let (total_swapped_amount, _) = swap_and_deposit(&e, &caller, &path, amount, min_shares, deadline)?;
+
+        Ok(total_swapped_amount)
+    }
+
+    /// Deposit the underlying asset directly, skipping the swap
+    ///
+    /// For callers who already hold the underlying asset: a same-token swap would fail (there
+    /// is no pair) and only waste budget.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `amount`: Amount of the underlying asset to deposit
+    /// - `invest`: Invest the deposit into the vault's strategies right away; if the strategy
+    ///   rejects it, the deposit stays idle and `InvestFallback` is emitted (callers signing
+    ///   explicit auth entries instead of source-account auth must cover both deposit calls)
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the deposit reverts
+    ///
+    /// ## Returns:
+    /// Amount of vault shares minted
+    pub fn deposit_underlying(e: Env, caller: Address, amount: i128, invest: bool, min_shares: i128) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+
+        // User's signature authorizes the vault to transfer the underlying asset from their account
+        deposit_direct(&e, &caller, amount, invest, min_shares)
+    }
+
+    /// Zap native XLM into the vault
+    ///
+    /// Native XLM is already a token through its Stellar Asset Contract, which the router and
+    /// the vault use directly, so no manual wrapping step is needed. The caller's native balance
+    /// is checked up front for a clear error instead of a failure deep inside the swap. For an
+    /// XLM vault the swap is skipped and the XLM is deposited like in `deposit_underlying`. Like
+    /// `deposit`, idle vault funds are invested once they cross the auto-invest threshold.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `amount`: Amount of XLM (in stroops) to swap and deposit
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Amount of underlying asset deposited into the vault
+    pub fn deposit_native(e: Env, caller: Address, amount: i128, min_shares: i128, deadline: Option<u64>) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let native_asset = native_asset_address(&e);
+        if token::Client::new(&e, &native_asset).balance(&caller) < amount {
+            return Err(DeFindexError::InsufficientBalance);
+        }
+
+        let underlying_asset = get_underlying_asset_address(&e);
+        let deposited = if native_asset == underlying_asset {
+            // XLM vault: deposit directly, there is nothing to swap
+            ensure_not_expired(&e, deadline, DeFindexError::DeadlineExpired)?;
+            deposit_direct(&e, &caller, amount, false, min_shares)?;
+            amount
+        } else {
+            let path = SwapPath::direct(&e, &native_asset, &underlying_asset, DeFindexError::InvalidArgument)?;
+            let (total_swapped_amount, _) = swap_and_deposit(&e, &caller, &path, amount, min_shares, deadline)?;
+            total_swapped_amount
+        };
+
+        // Invest the vault's idle funds once they cross the admin-set threshold
+        auto_invest_idle(&e);
+
+        Ok(deposited)
+    }
+
+    /// Zap and stake: swap, deposit into the vault, then stake the received vault shares
+    ///
+    /// For vaults paired with a staking/rewards contract. The staking contract must be on the
+    /// admin-managed allow-list, otherwise the caller could be tricked into handing their
+    /// shares to an arbitrary contract.
+    ///
+    /// ## Token Flow:
+    /// ```
+    /// User (token_in) → Router → Pair → User (underlying_asset) → Vault → User (shares) → Staking
+    /// ```
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `token_in`: The token user is depositing (will be swapped to underlying asset)
+    /// - `amount`: Amount of `token_in` to swap and deposit
+    /// - `staking_contract`: Allow-listed staking contract that receives the vault shares
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Amount of vault shares staked
+    pub fn deposit_and_stake(e: Env, caller: Address, token_in: Address, amount: i128, staking_contract: Address, min_shares: i128, deadline: Option<u64>) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        if !is_staking_contract_allowed(&e, &staking_contract) {
+            return Err(DeFindexError::Unauthorized);
+        }
+
+        let underlying_asset = get_underlying_asset_address(&e);
+        let path = SwapPath::direct(&e, &token_in, &underlying_asset, DeFindexError::InvalidArgument)?;
+
+        let (_, shares_minted) = swap_and_deposit(&e, &caller, &path, amount, min_shares, deadline)?;
+
+        // Step 3: Stake the freshly minted shares
+        // User's signature authorizes the staking contract to pull the shares from their account
+        StakingClient::new(&e, &staking_contract).stake(&caller, &shares_minted);
+
+        Ok(shares_minted)
+    }
+
+    /// Add or remove a staking contract from the `deposit_and_stake` allow-list (admin only)
+    pub fn set_staking_contract(e: Env, admin: Address, staking_contract: Address, allowed: bool) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_staking_contract", vec![&e, staking_contract.into_val(&e), allowed.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        set_staking_contract_allowed(&e, &staking_contract, allowed);
+        Ok(())
+    }
+
+    /// Add or remove a vault from the `rebalance` allow-list (admin only)
+    pub fn set_rebalance_vault(e: Env, admin: Address, vault: Address, allowed: bool) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_rebalance_vault", vec![&e, vault.into_val(&e), allowed.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        set_vault_allowed(&e, &vault, allowed);
+        Ok(())
+    }
+
+    /// Set the maximum share of a pair's input reserve a zap swap may consume (admin only)
+    ///
+    /// Expressed in units of the denominator chosen at construction (e.g. 5% is 500 in bps or
+    /// 50_000 in ppm: a swap may use at most 5% of the reserve). Set to 0 to disable the
+    /// liquidity check.
+    pub fn set_liquidity_guard(e: Env, admin: Address, max_reserve: u32) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_liquidity_guard", vec![&e, max_reserve.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        if max_reserve as i128 > get_denominator(&e) {
+            return Err(DeFindexError::InvalidArgument);
+        }
+        set_max_reserve(&e, max_reserve);
+        Ok(())
+    }
+
+    /// Returns the denominator of the liquidity guard
+    ///
+    /// 10_000 for basis points, 1_000_000 for parts per million.
+    pub fn get_denominator(e: Env) -> i128 {
+        get_denominator(&e)
+    }
+
+    /// Set the maximum number of tokens in a multi-hop swap path (admin only)
+    ///
+    /// Every hop is an extra pair call, so the cap bounds the budget a single swap can burn.
+    /// Defaults to `DEFAULT_MAX_PATH_LEN`; must allow at least one hop (2 tokens).
+    pub fn set_max_path_len(e: Env, admin: Address, max_path_len: u32) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_max_path_len", vec![&e, max_path_len.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        if max_path_len < 2 {
+            return Err(DeFindexError::InvalidArgument);
+        }
+        set_max_path_len(&e, max_path_len);
+        Ok(())
+    }
+
+    /// Returns the maximum number of tokens in a multi-hop swap path
+    pub fn get_max_path_len(e: Env) -> u32 {
+        get_max_path_len(&e)
+    }
+
+    /// Point the zapper at a new underlying asset (admin only)
+    ///
+    /// Use when the vault migrates its underlying; every zap then swaps into `new_underlying`.
+    /// Emits `UnderlyingAssetUpdated`.
+    pub fn set_underlying_asset(e: Env, admin: Address, new_underlying: Address) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_underlying_asset", vec![&e, new_underlying.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        let old_underlying = get_underlying_asset_address(&e);
+        set_underlying_asset_address(&e, new_underlying.clone());
+        UnderlyingAssetUpdated { old_underlying, new_underlying }.publish(&e);
+        Ok(())
+    }
+
+    /// Invest the vault's idle underlying asset into its first active strategy (admin only)
+    ///
+    /// For vaults where investing is a separate keeper action rather than part of each
+    /// deposit. This contract must be the vault's rebalance manager.
+    ///
+    /// ## Returns:
+    /// Amount of underlying asset invested (0 if nothing was idle). Fails with `InvestFailed`
+    /// if every strategy is paused or the vault rejects the rebalance.
+    pub fn invest_idle(e: Env, admin: Address) -> Result<i128, DeFindexError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        invest_idle_above(&e, 0)
+    }
+
+    /// Set the idle balance above which `deposit` invests the vault's idle funds (admin only)
+    ///
+    /// Amortizes keeper costs: the deposit that pushes the vault's idle underlying asset to
+    /// `threshold` or more also invests it, like `invest_idle`. Set to 0 to disable.
+    pub fn set_auto_invest_threshold(e: Env, admin: Address, threshold: i128) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_auto_invest_threshold", vec![&e, threshold.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        check_nonnegative_amount(threshold)?;
+        set_auto_invest_threshold(&e, threshold);
+        Ok(())
+    }
+
+    /// Schedule a timelocked admin operation (admin only)
+    ///
+    /// While the timelock delay is non-zero, every admin setter only runs once its
+    /// `op_hash` (see `get_op_hash`) has been scheduled and `execute_after` has passed.
+    /// `execute_after` must be at least `now + timelock delay`.
+    pub fn schedule(e: Env, admin: Address, op_hash: BytesN<32>, execute_after: u64) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::schedule(&e, &op_hash, execute_after)?;
+        Ok(())
+    }
+
+    /// Cancel a scheduled admin operation (admin only)
+    pub fn cancel(e: Env, admin: Address, op_hash: BytesN<32>) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::cancel(&e, &op_hash);
+        Ok(())
+    }
+
+    /// Set the timelock delay in seconds (admin only, 0 = disabled)
+    ///
+    /// Changing the delay is itself timelocked once a delay is in place, so a compromised
+    /// admin can't disable it instantly. At most `MAX_TIMELOCK_DELAY` (30 days).
+    pub fn set_timelock_delay(e: Env, admin: Address, delay: u64) -> Result<(), DeFindexError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::set_delay(&e, delay)?;
+        Ok(())
+    }
+
+    /// Returns the current timelock delay in seconds (0 = disabled)
+    pub fn get_timelock_delay(e: Env) -> u64 {
+        timelock::get_delay(&e)
+    }
+
+    /// Returns the hash to `schedule` for calling `fn_name` with `args` (without `admin`)
+    pub fn get_op_hash(e: Env, fn_name: Symbol, args: Vec<Val>) -> BytesN<32> {
+        op_hash(&e, fn_name, args)
+    }
+
+    /// Simulate a `deposit` without executing it
+    ///
+    /// Returns everything a front-end needs for a confirmation screen: the swap route, the
+    /// expected underlying amount, the estimated vault shares and the effective price.
+    ///
+    /// ## Parameters:
+    /// - `token_in`: The token the user would deposit
+    /// - `amount`: Amount of `token_in` to swap and deposit
+    pub fn simulate_zap(e: Env, token_in: Address, amount: i128) -> Result<ZapSimulation, DeFindexError> {
+        check_nonnegative_amount(amount)?;
+        if amount == 0 {
+            return Err(DeFindexError::InvalidArgument);
+        }
 
-        // Get the vault's underlying asset (the target token for our swap)
         let underlying_asset = get_underlying_asset_address(&e);
+        let path = SwapPath::direct(&e, &token_in, &underlying_asset, DeFindexError::InvalidArgument)?;
 
-        // Step 1: Swap token_in → underlying_asset via Soroswap Router
         let soroswap_router_address = get_soroswap_router_address(&e);
         let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+        let expected_underlying = soroswap_router_client
+            .router_get_amounts_out(&amount, path.as_vec())
+            .last()
+            .unwrap();
 
-        // Build swap path (direct pair)
-        let mut path: Vec<Address> = Vec::new(&e);
-        path.push_back(token_in.clone());
-        path.push_back(underlying_asset.clone());
-
-        // Execute swap - tokens go from user → pair → back to user (as underlying_asset)
-        // User's signature authorizes the router to transfer token_in from their account
-        let swap_result = soroswap_router_client.swap_exact_tokens_for_tokens(
-            &amount,     // Exact amount of token_in to swap
-            &0,          // Minimum amount out (0 for simplicity; use slippage calculation in production)
-            &path,       // Swap route: token_in → underlying_asset
-            &caller,     // Recipient of swapped tokens (user receives underlying_asset)
-            &u64::MAX,   // Deadline (max for simplicity; use actual timestamp in production)
+        let defindex_vault_client = DeFindexVaultClient::new(&e, &get_vault_address(&e));
+        let expected_shares = estimate_shares(&e, &defindex_vault_client, &underlying_asset, expected_underlying)?;
+
+        let effective_price = mul_div(&e, expected_underlying, PRICE_SCALE, amount, Rounding::Down)?;
+
+        Ok(ZapSimulation {
+            path: path.as_vec().clone(),
+            expected_underlying,
+            expected_shares,
+            effective_price,
+        })
+    }
+
+    /// Returns the amount of underlying asset `shares` vault shares are currently worth
+    ///
+    /// Forwards to the vault's `get_asset_amounts_per_shares`, so UIs can show positions in
+    /// underlying terms.
+    pub fn shares_to_underlying(e: Env, shares: i128) -> Result<i128, DeFindexError> {
+        check_nonnegative_amount(shares)?;
+        let defindex_vault_client = DeFindexVaultClient::new(&e, &get_vault_address(&e));
+
+        Ok(defindex_vault_client.get_asset_amounts_per_shares(&shares).get_unchecked(0))
+    }
+
+    /// Returns the vault shares `underlying` of the underlying asset would currently mint
+    ///
+    /// The vault has no conversion view in this direction, so the pro-rata share math is
+    /// mirrored (see `estimate_shares`).
+    pub fn underlying_to_shares(e: Env, underlying: i128) -> Result<i128, DeFindexError> {
+        check_nonnegative_amount(underlying)?;
+        let defindex_vault_client = DeFindexVaultClient::new(&e, &get_vault_address(&e));
+
+        estimate_shares(&e, &defindex_vault_client, &get_underlying_asset_address(&e), underlying)
+    }
+
+    /// Zap-out: redeem a percentage of the caller's vault shares and swap to `token_out`
+    ///
+    /// ## What This Does:
+    /// 1. Redeems `bps / 10_000` of the caller's vault shares for the vault's assets
+    /// 2. Swaps each redeemed asset → `token_out` via Soroswap Router (assets that already are
+    ///    `token_out` are kept as is) and sums the output. Each swap is subject to the liquidity
+    ///    guard and emits `SwapExecuted`, like the zap-in swaps
+    ///
+    /// Works for single- and multi-asset vaults alike.
+    ///
+    /// ## Token Flow:
+    /// ```
+    /// User (shares) → Vault → User (assets) → Router → Pair(s) → User (token_out)
+    /// ```
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user withdrawing (must sign the transaction)
+    /// - `bps`: Share of the caller's vault position to redeem, always in basis points regardless
+    ///   of the configured denominator (10_000 = everything)
+    /// - `token_out`: Token the user wants to receive
+    /// - `amount_out_min`: Minimum total amount of `token_out` to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Total amount of `token_out` received
+    pub fn withdraw_pct(e: Env, caller: Address, bps: u32, token_out: Address, amount_out_min: i128, deadline: Option<u64>) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount_out_min)?;
+        if bps == 0 || bps as i128 > BPS_DENOMINATOR {
+            return Err(DeFindexError::InvalidArgument);
+        }
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+        ensure_not_expired(&e, deadline, DeFindexError::DeadlineExpired)?;
+
+        // Step 1: Redeem the requested share of the caller's vault position
+        let defindex_vault_client = DeFindexVaultClient::new(&e, &get_vault_address(&e));
+        let assets = defindex_vault_client.get_assets();
+        let shares = mul_div(&e, defindex_vault_client.balance(&caller), bps as i128, BPS_DENOMINATOR, Rounding::Down)?;
+        if shares == 0 {
+            return Err(DeFindexError::InvalidArgument);
+        }
+
+        // One (zero) minimum per vault asset; slippage is enforced on the summed output below
+        let mut min_amounts_out: Vec<i128> = Vec::new(&e);
+        for _ in 0..assets.len() {
+            min_amounts_out.push_back(0);
+        }
+
+        // User's signature authorizes the vault to burn their shares
+        let amounts_withdrawn = defindex_vault_client.withdraw(
+            &shares,          // Shares to redeem
+            &min_amounts_out, // Minimum amounts out (one per asset)
+            &caller,          // Owner of the shares (receives the assets)
         );
+        if amounts_withdrawn.len() != assets.len() {
+            return Err(DeFindexError::InvalidArgument);
+        }
+
+        // Step 2: Swap every redeemed asset → token_out
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let mut total_out: i128 = 0;
+        for (asset, amount) in assets.iter().zip(amounts_withdrawn.iter()) {
+            if amount == 0 {
+                continue;
+            }
+            // Withdrawing straight into token_out needs no swap (and has no pair)
+            let amount_out = if asset.address == token_out {
+                amount
+            } else {
+                // Same guards and event as the zap swaps; the minimum is enforced on the total below
+                let path = SwapPath::direct(&e, &asset.address, &token_out, DeFindexError::InvalidArgument)?;
+                check_liquidity(&e, &soroswap_router_client, &path, amount)?;
+                swap_step(&e, &soroswap_router_client, &path, amount, &caller, deadline)?
+            };
+            total_out = total_out.checked_add(amount_out).ok_or(DeFindexError::ArithmeticError)?;
+        }
 
-        // Get amount of underlying_asset received from swap
-        let total_swapped_amount = swap_result.last().unwrap();
+        if total_out < amount_out_min {
+            return Err(DeFindexError::InsufficientOutput);
+        }
 
-        // Step 2: Deposit the swapped underlying_asset into DeFindex vault
+        Ok(total_out)
+    }
+
+    /// Zap through the best of several candidate routes
+    ///
+    /// Each candidate is quoted with `router_get_amounts_out`; the one yielding the most
+    /// underlying asset is used for the swap, then the output is deposited like `deposit`.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `token_in`: The token user is depositing (first token of every candidate)
+    /// - `amount`: Amount of `token_in` to swap and deposit
+    /// - `candidate_paths`: Up to `MAX_CANDIDATE_PATHS` routes ending in the underlying asset
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `(index of the chosen candidate, amount of underlying asset deposited)`
+    pub fn best_path_deposit(e: Env, caller: Address, token_in: Address, amount: i128, candidate_paths: Vec<Vec<Address>>, min_shares: i128, deadline: Option<u64>) -> Result<(u32, i128), DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let underlying_asset = get_underlying_asset_address(&e);
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let quote = |path: &SwapPath| match soroswap_router_client.try_router_get_amounts_out(&amount, path.as_vec()) {
+            Ok(Ok(amounts)) => Some(amounts.last_unchecked()),
+            _ => None,
+        };
+        let (index, path) = select_best(candidate_paths, &token_in, &underlying_asset, get_max_path_len(&e), quote, DeFindexError::InvalidArgument, DeFindexError::SwapFailed)?;
+        let (total_swapped_amount, _) = swap_and_deposit(&e, &caller, &path, amount, min_shares, deadline)?;
+
+        Ok((index, total_swapped_amount))
+    }
+
+    /// Zap with this contract taking custody and building the auth context itself
+    ///
+    /// For callers whose signature only covers this contract (non-root auth, e.g. another
+    /// contract or a smart wallet): the zapper pulls `token_in`, swaps and deposits as itself,
+    /// pre-authorizing the router's and the vault's token pulls with `authorize_transfer`,
+    /// then transfers the minted shares to `caller`.
+    /// Token flow: User → Contract → Router (Pair) → Contract → Vault, shares → User
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user depositing (must sign the transaction)
+    /// - `token_in`: The token user is depositing
+    /// - `amount`: Amount of `token_in` to swap and deposit
+    /// - `min_shares`: Minimum vault shares to receive, otherwise the zap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Vault shares transferred to `caller`
+    pub fn deposit_custodial(e: Env, caller: Address, token_in: Address, amount: i128, min_shares: i128, deadline: Option<u64>) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+        ensure_not_expired(&e, deadline, DeFindexError::DeadlineExpired)?;
+
+        let this = e.current_contract_address();
+        let underlying_asset = get_underlying_asset_address(&e);
+        let path = SwapPath::direct(&e, &token_in, &underlying_asset, DeFindexError::InvalidArgument)?;
+
+        // Step 1: Take custody of token_in
+        safe_transfer(&e, &token_in, &caller, &this, amount, DeFindexError::TransferMismatch)?;
+
+        // Step 2: Swap as this contract; the router pulls token_in from `to` into the pair
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &get_soroswap_router_address(&e));
+        check_liquidity(&e, &soroswap_router_client, &path, amount)?;
+        let pair_address = soroswap_router_client.router_pair_for(&token_in, &underlying_asset);
+        authorize_transfer(&e, &token_in, &pair_address, amount);
+        let total_swapped_amount = swap_step(&e, &soroswap_router_client, &path, amount, &this, deadline)?;
+
+        // Step 3: Deposit as this contract; the vault pulls the underlying asset from `from`
         let defindex_vault_address = get_vault_address(&e);
         let defindex_vault_client = DeFindexVaultClient::new(&e, &defindex_vault_address);
+        authorize_transfer(&e, &underlying_asset, &defindex_vault_address, total_swapped_amount);
+        // Depositor is this contract, the shares are forwarded below
+        let shares_minted = deposit_step(&e, &defindex_vault_client, total_swapped_amount, &this)?;
+        if shares_minted < min_shares {
+            return Err(DeFindexError::InsufficientShares);
+        }
+
+        // Step 4: Hand the shares to the caller
+        safe_transfer(&e, &defindex_vault_address, &this, &caller, shares_minted, DeFindexError::TransferMismatch)?;
+
+        Ok(shares_minted)
+    }
 
-        // Deposit into vault - user's signature authorizes vault to transfer from their account
-        defindex_vault_client.deposit(
-            &vec![&e, total_swapped_amount],  // Amounts array (for multi-asset vaults; currently single-asset)
-            &vec![&e, 0],                      // Minimum amounts out (slippage protection for multi-asset vaults)
-            &caller,                            // Depositor (receives vault shares)
-            &false                              // invest: false = keep as idle in vault; true = invest into strategy
+    /// Move a position between two DeFindex vaults in one transaction
+    ///
+    /// Withdraws `shares` from `from_vault`, swaps along `bridge_path` when the two vaults have
+    /// different underlying assets, and deposits the result into `to_vault`. Both vaults must be
+    /// on the admin-managed allow-list and hold a single asset.
+    ///
+    /// ## Token Flow:
+    /// ```
+    /// User (shares) → from_vault → User (underlying) → Router → Pair → User → to_vault → User (shares)
+    /// ```
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user rebalancing (must sign the transaction)
+    /// - `from_vault`, `to_vault`: Allow-listed source and destination vaults
+    /// - `shares`: Amount of `from_vault` shares to redeem
+    /// - `bridge_path`: Route from `from_vault`'s to `to_vault`'s underlying (ignored if they match)
+    /// - `min_shares`: Minimum `to_vault` shares to receive, otherwise the rebalance reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Amount of `to_vault` shares minted
+    #[allow(clippy::too_many_arguments)]
+    pub fn rebalance(
+        e: Env,
+        caller: Address,
+        from_vault: Address,
+        to_vault: Address,
+        shares: i128,
+        bridge_path: Vec<Address>,
+        min_shares: i128,
+        deadline: Option<u64>,
+    ) -> Result<i128, DeFindexError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(shares)?;
+        check_nonnegative_amount(min_shares)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+        ensure_not_expired(&e, deadline, DeFindexError::DeadlineExpired)?;
+
+        if from_vault == to_vault {
+            return Err(DeFindexError::InvalidArgument);
+        }
+        if !is_vault_allowed(&e, &from_vault) || !is_vault_allowed(&e, &to_vault) {
+            return Err(DeFindexError::Unauthorized);
+        }
+
+        // Only single-asset vaults: a multi-asset position can't be bridged along one path
+        let from_vault_client = DeFindexVaultClient::new(&e, &from_vault);
+        let to_vault_client = DeFindexVaultClient::new(&e, &to_vault);
+        let from_assets = from_vault_client.get_assets();
+        let to_assets = to_vault_client.get_assets();
+        if from_assets.len() != 1 || to_assets.len() != 1 {
+            return Err(DeFindexError::InvalidArgument);
+        }
+        let from_underlying = from_assets.get_unchecked(0).address;
+        let to_underlying = to_assets.get_unchecked(0).address;
+
+        // Step 1: Redeem the shares; user's signature authorizes the vault to burn them
+        let amounts_withdrawn = from_vault_client.withdraw(
+            &shares,      // Shares to redeem
+            &vec![&e, 0], // Minimum amounts out (slippage is enforced through min_shares)
+            &caller,      // Owner of the shares (receives the underlying asset)
         );
+        let mut amount = amounts_withdrawn.get_unchecked(0);
 
-        // Return the amount deposited
-        Ok(total_swapped_amount)
+        // Step 2: Bridge the underlying assets if the vaults differ
+        if from_underlying != to_underlying {
+            let path = SwapPath::new(bridge_path, get_max_path_len(&e), DeFindexError::InvalidArgument)?;
+            if path.token_in() != from_underlying || path.token_out() != to_underlying {
+                return Err(DeFindexError::InvalidArgument);
+            }
+
+            let soroswap_router_client = SoroswapRouterClient::new(&e, &get_soroswap_router_address(&e));
+            check_liquidity(&e, &soroswap_router_client, &path, amount)?;
+            // Everything redeemed from from_vault is bridged back to the caller
+            amount = swap_step(&e, &soroswap_router_client, &path, amount, &caller, deadline)?;
+        }
+
+        // Step 3: Deposit into to_vault
+        let shares_minted = deposit_step(&e, &to_vault_client, amount, &caller)?;
+        if shares_minted < min_shares {
+            return Err(DeFindexError::InsufficientShares);
+        }
+
+        Ok(shares_minted)
     }
 }
@@ -0,0 +1,34 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Zapper configuration passed to the constructor
+///
+/// Named fields make it impossible to silently swap e.g. the vault and router addresses,
+/// which is easy to do with positional `Address` arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ZapConfig {
+    /// Manages the zapper configuration (e.g. the staking contract allow-list)
+    pub admin: Address,
+    /// The DeFindex vault where deposits will be made
+    pub vault: Address,
+    /// The Soroswap router used for token swaps
+    pub router: Address,
+    /// The vault's underlying asset (target token for swaps)
+    pub underlying_asset: Address,
+    /// Unit of the liquidity guard: `BPS_DENOMINATOR` (10_000) or `PPM_DENOMINATOR` (1_000_000)
+    pub denominator: u32,
+}
+
+/// Dry-run result of a zap, returned by `simulate_zap`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ZapSimulation {
+    /// Swap route that would be used
+    pub path: Vec<Address>,
+    /// Expected amount of underlying asset received from the swap
+    pub expected_underlying: i128,
+    /// Estimated vault shares minted for the deposit
+    pub expected_shares: i128,
+    /// Underlying received per unit of `token_in`, scaled by `PRICE_SCALE`
+    pub effective_price: i128,
+}
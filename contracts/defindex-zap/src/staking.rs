@@ -0,0 +1,10 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Minimal interface of a staking/rewards contract paired with a DeFindex vault
+///
+/// `stake` is expected to pull `amount` vault shares from `from` (who must authorize it).
+#[allow(dead_code)]
+#[contractclient(name = "StakingClient")]
+pub trait Staking {
+    fn stake(e: Env, from: Address, amount: i128);
+}
@@ -4,9 +4,16 @@ use soroban_sdk::{contracttype, Address, Env};
 #[contracttype]
 
 enum DataKey {
+    Admin,
     DeFindexVaultAddress,
     SoroswapRouterAddress,
-    UnderlyingAsset
+    UnderlyingAsset,
+    AllowedStakingContract(Address),
+    AllowedVault(Address),
+    MaxReserve,
+    Denominator,
+    AutoInvestThreshold,
+    MaxPathLen,
 }
 
 #[allow(dead_code)]
@@ -15,6 +22,10 @@ const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 #[allow(dead_code)]
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 pub fn extend_instance_ttl(e: &Env) {
     e.storage()
@@ -22,6 +33,20 @@ pub fn extend_instance_ttl(e: &Env) {
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
+// Admin
+pub fn set_admin(e: &Env, address: Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Admin, &address);
+}
+
+pub fn get_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap()
+}
+
 // DeFindex Vault Address
 pub fn set_vault_address(e: &Env, address: Address) {
     e.storage()
@@ -63,3 +88,97 @@ pub fn get_underlying_asset_address(e: &Env) -> Address {
         .get(&DataKey::UnderlyingAsset)
         .unwrap()
 }
+
+// Staking contracts allowed in `deposit_and_stake`
+pub fn set_staking_contract_allowed(e: &Env, address: &Address, allowed: bool) {
+    let key = DataKey::AllowedStakingContract(address.clone());
+    if allowed {
+        e.storage().persistent().set(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_staking_contract_allowed(e: &Env, address: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::AllowedStakingContract(address.clone()))
+}
+
+// Vaults allowed as source or destination in `rebalance`
+pub fn set_vault_allowed(e: &Env, address: &Address, allowed: bool) {
+    let key = DataKey::AllowedVault(address.clone());
+    if allowed {
+        e.storage().persistent().set(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_vault_allowed(e: &Env, address: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::AllowedVault(address.clone()))
+}
+
+// Maximum share of a pair's input reserve a swap may consume (in denominator units, 0 = disabled)
+pub fn set_max_reserve(e: &Env, max_reserve: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::MaxReserve, &max_reserve);
+}
+
+pub fn get_max_reserve(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MaxReserve)
+        .unwrap_or(0)
+}
+
+// Denominator of the liquidity guard (`BPS_DENOMINATOR` or `PPM_DENOMINATOR`)
+pub fn set_denominator(e: &Env, denominator: i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Denominator, &denominator);
+}
+
+pub fn get_denominator(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::Denominator)
+        .unwrap_or(crate::BPS_DENOMINATOR)
+}
+
+// Idle balance above which `deposit` invests the vault's idle funds (0 = disabled)
+pub fn set_auto_invest_threshold(e: &Env, threshold: i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::AutoInvestThreshold, &threshold);
+}
+
+pub fn get_auto_invest_threshold(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::AutoInvestThreshold)
+        .unwrap_or(0)
+}
+
+// Maximum number of tokens in a multi-hop swap path
+pub fn set_max_path_len(e: &Env, max_path_len: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::MaxPathLen, &max_path_len);
+}
+
+pub fn get_max_path_len(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MaxPathLen)
+        .unwrap_or(workshop_common::path::DEFAULT_MAX_PATH_LEN)
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    vec, IntoVal, Val, Vec,
+};
+use workshop_common::time::DEFAULT_DEADLINE_WINDOW;
+
+use super::Setup;
+
+/// Authorizes the user's signature over a `deposit` of `signed_amount` token_in, and the router
+/// and vault pulls it covers
+fn sign_deposit(setup: &Setup, signed_amount: i128) {
+    let path = vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()];
+    let underlying_amount = 2 * signed_amount;
+    let deposit_args: Vec<Val> = (setup.token_in.clone(), signed_amount, None::<u64>, false, 0i128, signed_amount).into_val(&setup.e);
+    let router_args: Vec<Val> = (signed_amount, 0i128, path, setup.user.clone(), DEFAULT_DEADLINE_WINDOW).into_val(&setup.e);
+    let router_pull_args: Vec<Val> = (setup.user.clone(), setup.router.address.clone(), signed_amount).into_val(&setup.e);
+    let vault_args: Vec<Val> = (vec![&setup.e, underlying_amount], vec![&setup.e, 0i128], setup.user.clone(), false).into_val(&setup.e);
+    let vault_pull_args: Vec<Val> = (setup.user.clone(), setup.vault.address.clone(), underlying_amount).into_val(&setup.e);
+    setup.e.mock_auths(&[MockAuth {
+        address: &setup.user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "deposit",
+            args: deposit_args,
+            sub_invokes: &[
+                MockAuthInvoke {
+                    contract: &setup.router.address,
+                    fn_name: "swap_exact_tokens_for_tokens",
+                    args: router_args,
+                    sub_invokes: &[MockAuthInvoke {
+                        contract: &setup.token_in,
+                        fn_name: "transfer",
+                        args: router_pull_args,
+                        sub_invokes: &[],
+                    }],
+                },
+                MockAuthInvoke {
+                    contract: &setup.vault.address,
+                    fn_name: "deposit",
+                    args: vault_args,
+                    sub_invokes: &[MockAuthInvoke {
+                        contract: &setup.underlying,
+                        fn_name: "transfer",
+                        args: vault_pull_args,
+                        sub_invokes: &[],
+                    }],
+                },
+            ],
+        },
+    }]);
+}
+
+#[test]
+fn signature_authorizes_the_signed_amount() {
+    let setup = Setup::new();
+
+    sign_deposit(&setup, 100);
+    let deposited = setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+
+    assert_eq!(deposited, 200);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+}
+
+#[test]
+fn signature_scoped_to_one_amount_cannot_deposit_another() {
+    let setup = Setup::new();
+
+    sign_deposit(&setup, 100);
+    let result = setup.contract.try_deposit(&setup.user, &setup.token_in, &50, &None, &false, &0, &100);
+
+    assert!(result.is_err());
+    assert_eq!(setup.router.swap_calls(), 0);
+    assert_eq!(setup.vault.balance(&setup.user), 0);
+}
@@ -0,0 +1,21 @@
+use super::Setup;
+
+#[test]
+fn compound_first_claims_before_depositing() {
+    let setup = Setup::new();
+
+    let deposited = setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &true, &0, &100);
+
+    assert_eq!(deposited, 200);
+    assert_eq!(setup.vault.claimed(&setup.user), 1);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+}
+
+#[test]
+fn plain_deposit_does_not_claim() {
+    let setup = Setup::new();
+
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+
+    assert_eq!(setup.vault.claimed(&setup.user), 0);
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{testutils::Address as _, Address};
+
+use super::Setup;
+use crate::{DeFindexSimple, ZapConfig, BPS_DENOMINATOR};
+
+fn config(setup: &Setup, underlying_asset: Address) -> ZapConfig {
+    ZapConfig {
+        admin: setup.admin.clone(),
+        vault: setup.vault.address.clone(),
+        router: setup.router.address.clone(),
+        underlying_asset,
+        denominator: BPS_DENOMINATOR as u32,
+    }
+}
+
+#[test]
+fn constructor_accepts_a_stellar_asset_underlying() {
+    // `Setup` deploys with the decimals probe on
+    let setup = Setup::new();
+    assert_eq!(setup.contract.get_config().underlying_asset, setup.underlying);
+}
+
+#[test]
+#[should_panic(expected = "underlying_asset is not a valid token contract")]
+fn constructor_rejects_a_non_token_underlying() {
+    let setup = Setup::new();
+    let not_a_token = Address::generate(&setup.e);
+    setup.e.register(DeFindexSimple, (config(&setup, not_a_token), true));
+}
+
+#[test]
+fn decimals_probe_is_opt_out() {
+    let setup = Setup::new();
+    let not_a_token = Address::generate(&setup.e);
+    setup.e.register(DeFindexSimple, (config(&setup, not_a_token), false));
+}
+
+#[test]
+fn get_config_reads_back_every_field() {
+    let setup = Setup::new();
+
+    let config = setup.contract.get_config();
+
+    assert_eq!(config.admin, setup.admin);
+    assert_eq!(config.vault, setup.vault.address);
+    assert_eq!(config.router, setup.router.address);
+    assert_eq!(config.underlying_asset, setup.underlying);
+    assert_eq!(config.denominator, BPS_DENOMINATOR as u32);
+}
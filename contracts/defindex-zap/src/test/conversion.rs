@@ -0,0 +1,32 @@
+use soroban_sdk::token;
+
+use super::Setup;
+use crate::error::DeFindexError;
+
+#[test]
+fn empty_vault_converts_one_to_one() {
+    let setup = Setup::new();
+
+    assert_eq!(setup.contract.underlying_to_shares(&100), 100);
+    assert_eq!(setup.contract.shares_to_underlying(&100), 0);
+}
+
+#[test]
+fn conversions_follow_the_vault_share_price() {
+    let setup = Setup::new();
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    // Vault gains: 200 shares now back 400 underlying
+    token::StellarAssetClient::new(&setup.e, &setup.underlying).mint(&setup.vault.address, &200);
+
+    assert_eq!(setup.contract.shares_to_underlying(&100), 200);
+    assert_eq!(setup.contract.shares_to_underlying(&200), setup.vault.get_asset_amounts_per_shares(&200).get_unchecked(0));
+    assert_eq!(setup.contract.underlying_to_shares(&200), 100);
+}
+
+#[test]
+fn negative_amounts_are_rejected() {
+    let setup = Setup::new();
+
+    assert_eq!(setup.contract.try_shares_to_underlying(&-1), Err(Ok(DeFindexError::NegativeNotAllowed)));
+    assert_eq!(setup.contract.try_underlying_to_shares(&-1), Err(Ok(DeFindexError::NegativeNotAllowed)));
+}
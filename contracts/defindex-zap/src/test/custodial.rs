@@ -0,0 +1,54 @@
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    IntoVal, Val, Vec,
+};
+
+use super::{Setup, FUNDS};
+use crate::error::DeFindexError;
+
+/// Authorizes only what `caller` signs: the zap call and its pull of `token_in`
+///
+/// Every other transfer (the router's and the vault's pulls from the zapper) must be covered
+/// by the zapper's own `authorize_as_current_contract` entries.
+fn mock_caller_auth(setup: &Setup, amount: i128, min_shares: i128) {
+    let zap_args: Vec<Val> = (setup.user.clone(), setup.token_in.clone(), amount, min_shares, None::<u64>).into_val(&setup.e);
+    let pull_args: Vec<Val> = (setup.user.clone(), setup.contract.address.clone(), amount).into_val(&setup.e);
+    setup.e.mock_auths(&[MockAuth {
+        address: &setup.user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "deposit_custodial",
+            args: zap_args,
+            sub_invokes: &[MockAuthInvoke {
+                contract: &setup.token_in,
+                fn_name: "transfer",
+                args: pull_args,
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+}
+
+#[test]
+fn custodial_deposit_authorizes_the_vault_pull_as_the_zapper() {
+    let setup = Setup::new();
+
+    mock_caller_auth(&setup, 100, 200);
+    let shares = setup.contract.deposit_custodial(&setup.user, &setup.token_in, &100, &200, &None);
+
+    assert_eq!(shares, 200);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+    assert_eq!(setup.vault.balance(&setup.contract.address), 0);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100);
+    assert_eq!(setup.balance(&setup.underlying, &setup.contract.address), 0);
+}
+
+#[test]
+fn custodial_deposit_enforces_min_shares() {
+    let setup = Setup::new();
+
+    mock_caller_auth(&setup, 100, 201);
+    let result = setup.contract.try_deposit_custodial(&setup.user, &setup.token_in, &100, &201, &None);
+
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{testutils::Events, token, Address, Map, Symbol, TryFromVal, Val};
+
+use super::Setup;
+use crate::error::DeFindexError;
+
+/// Gives the user 1_000 of the underlying asset to deposit directly
+fn holding_underlying() -> Setup<'static> {
+    let setup = Setup::new();
+    token::StellarAssetClient::new(&setup.e, &setup.underlying).mint(&setup.user, &1_000);
+    setup
+}
+
+#[test]
+fn deposit_underlying_skips_the_router() {
+    let setup = holding_underlying();
+
+    let shares = setup.contract.deposit_underlying(&setup.user, &100, &false, &100);
+
+    assert_eq!(shares, 100);
+    assert_eq!(setup.vault.balance(&setup.user), 100);
+    assert_eq!(setup.balance(&setup.underlying, &setup.user), 900);
+    assert_eq!(setup.router.swap_calls(), 0);
+}
+
+#[test]
+fn deposit_underlying_can_invest_right_away() {
+    let setup = holding_underlying();
+
+    setup.contract.deposit_underlying(&setup.user, &100, &true, &100);
+
+    let allocation = setup.vault.fetch_total_managed_funds().get_unchecked(0);
+    assert_eq!((allocation.idle_amount, allocation.invested_amount), (0, 100));
+}
+
+#[test]
+fn deposit_underlying_enforces_min_shares() {
+    let setup = holding_underlying();
+
+    let result = setup.contract.try_deposit_underlying(&setup.user, &100, &false, &101);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+}
+
+#[test]
+fn rejected_invest_falls_back_to_an_idle_deposit() {
+    let setup = holding_underlying();
+    setup.vault.set_fail_invest(&true);
+
+    let shares = setup.contract.deposit_underlying(&setup.user, &100, &true, &100);
+
+    // Events only cover the last invocation: read them first
+    let fallback = setup.e.events().all().iter().find(|(contract, topics, _)| {
+        let name = topics.first().and_then(|topic| Symbol::try_from_val(&setup.e, &topic).ok());
+        *contract == setup.contract.address && name == Some(Symbol::new(&setup.e, "invest_fallback"))
+    });
+    let (_, _, data) = fallback.expect("no InvestFallback event");
+    let fields = Map::<Symbol, Val>::try_from_val(&setup.e, &data).unwrap();
+    assert_eq!(Address::try_from_val(&setup.e, &fields.get(Symbol::new(&setup.e, "depositor")).unwrap()).unwrap(), setup.user);
+    assert_eq!(i128::try_from_val(&setup.e, &fields.get(Symbol::new(&setup.e, "amount")).unwrap()).unwrap(), 100);
+
+    assert_eq!(shares, 100);
+    let allocation = setup.vault.fetch_total_managed_funds().get_unchecked(0);
+    assert_eq!((allocation.idle_amount, allocation.invested_amount), (100, 0));
+}
@@ -0,0 +1,87 @@
+use soroban_sdk::{testutils::Events, Symbol, TryFromVal};
+
+use super::Setup;
+use crate::error::DeFindexError;
+
+/// Makes the zapper the vault's rebalance manager and invests once 300 underlying is idle
+fn auto_investing() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.vault.set_rebalance_manager(&setup.contract.address);
+    setup.contract.set_auto_invest_threshold(&setup.admin, &300);
+    setup
+}
+
+fn idle_and_invested(setup: &Setup) -> (i128, i128) {
+    let allocation = setup.vault.fetch_total_managed_funds().get_unchecked(0);
+    (allocation.idle_amount, allocation.invested_amount)
+}
+
+fn invest_skipped_published(setup: &Setup) -> bool {
+    setup.e.events().all().iter().any(|(contract, topics, _)| {
+        let name = topics.first().and_then(|topic| Symbol::try_from_val(&setup.e, &topic).ok());
+        contract == setup.contract.address && name == Some(Symbol::new(&setup.e, "invest_skipped"))
+    })
+}
+
+#[test]
+fn deposit_invests_once_idle_funds_cross_the_threshold() {
+    let setup = auto_investing();
+
+    // 200 idle: below the threshold, nothing is invested
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    assert_eq!(setup.vault.invest_calls(), 0);
+    assert_eq!(idle_and_invested(&setup), (200, 0));
+
+    // 400 idle: this deposit invests all of it
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    assert_eq!(setup.vault.invest_calls(), 1);
+    assert_eq!(idle_and_invested(&setup), (0, 400));
+    assert_eq!(setup.vault.balance(&setup.user), 400);
+}
+
+#[test]
+fn deposit_succeeds_when_the_auto_invest_fails() {
+    let setup = auto_investing();
+    setup.vault.set_fail_invest(&true);
+
+    setup.contract.deposit(&setup.user, &setup.token_in, &200, &None, &false, &0, &200);
+
+    assert!(invest_skipped_published(&setup));
+    assert_eq!(setup.vault.invest_calls(), 0);
+    assert_eq!(idle_and_invested(&setup), (400, 0));
+    assert_eq!(setup.vault.balance(&setup.user), 400);
+}
+
+#[test]
+fn invest_idle_propagates_vault_errors() {
+    let setup = Setup::new();
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+
+    // Not the rebalance manager yet
+    assert_eq!(setup.contract.try_invest_idle(&setup.admin), Err(Ok(DeFindexError::InvestFailed)));
+
+    setup.vault.set_rebalance_manager(&setup.contract.address);
+    setup.vault.set_strategy_paused(&true);
+    assert_eq!(setup.contract.try_invest_idle(&setup.admin), Err(Ok(DeFindexError::InvestFailed)));
+
+    setup.vault.set_strategy_paused(&false);
+    assert_eq!(setup.contract.invest_idle(&setup.admin), 200);
+    assert_eq!(idle_and_invested(&setup), (0, 200));
+}
+
+#[test]
+fn invest_idle_records_an_invest_call_on_the_vault() {
+    let setup = Setup::new();
+    setup.vault.set_rebalance_manager(&setup.contract.address);
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+
+    assert_eq!(setup.contract.try_invest_idle(&setup.user), Err(Ok(DeFindexError::Unauthorized)));
+    assert_eq!(setup.vault.invest_calls(), 0);
+
+    assert_eq!(setup.contract.invest_idle(&setup.admin), 200);
+    assert_eq!(setup.vault.invest_calls(), 1);
+
+    // Nothing idle left: no further invest call
+    assert_eq!(setup.contract.invest_idle(&setup.admin), 0);
+    assert_eq!(setup.vault.invest_calls(), 1);
+}
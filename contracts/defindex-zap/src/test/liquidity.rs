@@ -0,0 +1,34 @@
+use super::Setup;
+use crate::error::DeFindexError;
+
+/// 1% liquidity guard against a pool holding only 1_000 token_in
+fn small_pool() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.contract.set_liquidity_guard(&setup.admin, &100);
+    setup.router.set_reserves(&setup.token_in, &setup.underlying, &1_000, &2_000);
+    setup
+}
+
+#[test]
+fn deposit_reverts_above_the_reserve_fraction() {
+    let setup = small_pool();
+
+    let result = setup.contract.try_deposit(&setup.user, &setup.token_in, &11, &None, &false, &0, &11);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientLiquidity)));
+    assert_eq!(setup.router.swap_calls(), 0);
+}
+
+#[test]
+fn deposit_passes_within_the_reserve_fraction() {
+    let setup = small_pool();
+
+    assert_eq!(setup.contract.deposit(&setup.user, &setup.token_in, &10, &None, &false, &0, &10), 20);
+}
+
+#[test]
+fn zero_guard_disables_the_check() {
+    let setup = small_pool();
+    setup.contract.set_liquidity_guard(&setup.admin, &0);
+
+    assert_eq!(setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100), 200);
+}
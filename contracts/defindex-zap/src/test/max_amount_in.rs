@@ -0,0 +1,21 @@
+use super::{Setup, FUNDS};
+use crate::error::DeFindexError;
+
+#[test]
+fn amount_above_the_cap_is_rejected() {
+    let setup = Setup::new();
+
+    let result = setup.contract.try_deposit(&setup.user, &setup.token_in, &101, &None, &false, &0, &100);
+
+    assert_eq!(result, Err(Ok(DeFindexError::AmountExceedsMax)));
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS);
+    assert_eq!(setup.router.swap_calls(), 0);
+}
+
+#[test]
+fn amount_at_the_cap_is_accepted() {
+    let setup = Setup::new();
+
+    assert_eq!(setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100), 200);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100);
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{token, vec};
+use workshop_mocks::{MockStaking, MockStakingClient};
+
+use super::Setup;
+use crate::error::DeFindexError;
+
+// Every zap swaps 100 token_in for 200 underlying, minting 200 shares in the empty vault
+
+#[test]
+fn deposit_with_path_enforces_min_shares() {
+    let setup = Setup::new();
+    let path = vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()];
+
+    let result = setup.contract.try_deposit_with_path(&setup.user, &path, &100, &201, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+
+    assert_eq!(setup.contract.deposit_with_path(&setup.user, &path, &100, &200, &None), 200);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+}
+
+#[test]
+fn deposit_and_stake_enforces_min_shares() {
+    let setup = Setup::new();
+    let staking = MockStakingClient::new(&setup.e, &setup.e.register(MockStaking, (setup.vault.address.clone(),)));
+    setup.contract.set_staking_contract(&setup.admin, &staking.address, &true);
+
+    let result = setup.contract.try_deposit_and_stake(&setup.user, &setup.token_in, &100, &staking.address, &201, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+
+    assert_eq!(setup.contract.deposit_and_stake(&setup.user, &setup.token_in, &100, &staking.address, &200, &None), 200);
+    assert_eq!(staking.staked(&setup.user), 200);
+}
+
+#[test]
+fn best_path_deposit_enforces_min_shares() {
+    let setup = Setup::new();
+    let candidates = vec![&setup.e, vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()]];
+
+    let result = setup.contract.try_best_path_deposit(&setup.user, &setup.token_in, &100, &candidates, &201, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+
+    assert_eq!(setup.contract.best_path_deposit(&setup.user, &setup.token_in, &100, &candidates, &200, &None), (0, 200));
+}
+
+#[test]
+fn negative_min_shares_is_rejected() {
+    let setup = Setup::new();
+    let candidates = vec![&setup.e, vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()]];
+
+    let result = setup.contract.try_best_path_deposit(&setup.user, &setup.token_in, &100, &candidates, &-1, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::NegativeNotAllowed)));
+}
+
+#[test]
+fn deposit_checks_the_shares_the_vault_returns() {
+    let setup = Setup::new();
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    // Vault gains: the next 200 underlying only mints 100 shares
+    token::StellarAssetClient::new(&setup.e, &setup.underlying).mint(&setup.vault.address, &200);
+
+    let result = setup.contract.try_deposit(&setup.user, &setup.token_in, &100, &None, &false, &101, &100);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &100, &100);
+    assert_eq!(setup.vault.balance(&setup.user), 300);
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+use workshop_mocks::{MockRouter, MockRouterClient, MockVault, MockVaultClient};
+
+use crate::{DeFindexSimple, DeFindexSimpleClient, ZapConfig, BPS_DENOMINATOR};
+
+mod auth;
+mod compound;
+mod constructor;
+mod conversion;
+mod custodial;
+mod direct;
+mod invest;
+mod liquidity;
+mod max_amount_in;
+mod min_shares;
+mod native;
+mod rebalance;
+mod receipt;
+mod underlying;
+mod simulate;
+mod stake;
+mod withdraw;
+
+/// Starting balance of `user` in `token_in` and of the router in `underlying`
+pub const FUNDS: i128 = 1_000_000_000;
+
+/// A zapper in front of a mock router swapping `token_in -> underlying` at 1:2 and a mock
+/// single-asset vault of `underlying`
+pub struct Setup<'a> {
+    pub e: Env,
+    pub admin: Address,
+    pub user: Address,
+    pub token_in: Address,
+    pub underlying: Address,
+    pub router: MockRouterClient<'a>,
+    pub vault: MockVaultClient<'a>,
+    pub contract: DeFindexSimpleClient<'a>,
+}
+
+impl Setup<'_> {
+    pub fn new() -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let admin = Address::generate(&e);
+        let user = Address::generate(&e);
+        let token_in = e.register_stellar_asset_contract_v2(admin.clone()).address();
+        let underlying = e.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let router = MockRouterClient::new(&e, &e.register(MockRouter, ()));
+        router.set_rate(&token_in, &underlying, &2, &1);
+        token::StellarAssetClient::new(&e, &token_in).mint(&user, &FUNDS);
+        token::StellarAssetClient::new(&e, &underlying).mint(&router.address, &FUNDS);
+
+        let strategy = Address::generate(&e);
+        let vault = MockVaultClient::new(&e, &e.register(MockVault, (vec![&e, underlying.clone()], strategy)));
+
+        let config = ZapConfig {
+            admin: admin.clone(),
+            vault: vault.address.clone(),
+            router: router.address.clone(),
+            underlying_asset: underlying.clone(),
+            denominator: BPS_DENOMINATOR as u32,
+        };
+        let contract = DeFindexSimpleClient::new(&e, &e.register(DeFindexSimple, (config, true)));
+
+        Setup { e, admin, user, token_in, underlying, router, vault, contract }
+    }
+
+    pub fn balance(&self, token: &Address, id: &Address) -> i128 {
+        token::Client::new(&self.e, token).balance(id)
+    }
+}
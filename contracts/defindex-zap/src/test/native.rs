@@ -0,0 +1,117 @@
+use soroban_sdk::{
+    contracttype,
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes,
+};
+use workshop_mocks::{MockVault, MockVaultClient};
+
+use super::Setup;
+use crate::{error::DeFindexError, DeFindexSimple, DeFindexSimpleClient, ZapConfig, BPS_DENOMINATOR};
+
+/// Storage layout of a Stellar Asset Contract balance
+#[contracttype]
+enum SacDataKey {
+    Balance(Address),
+}
+
+#[contracttype]
+struct SacBalance {
+    amount: i128,
+    authorized: bool,
+    clawback: bool,
+}
+
+/// Deploys the native XLM Stellar Asset Contract and credits `holder` with `amount` stroops
+///
+/// Native XLM has no issuer to mint it, so the balance is written to the asset contract's
+/// storage directly.
+fn native_asset(setup: &Setup, holder: &Address, amount: i128) -> Address {
+    let native = setup.e.deployer().with_stellar_asset(Bytes::from_array(&setup.e, &[0, 0, 0, 0])).deploy();
+    setup.e.as_contract(&native, || {
+        let balance = SacBalance { amount, authorized: true, clawback: false };
+        setup.e.storage().persistent().set(&SacDataKey::Balance(holder.clone()), &balance);
+    });
+    native
+}
+
+/// A zapper in front of an XLM vault, sharing the setup's router
+fn xlm_vault(setup: &Setup, native: &Address) -> (MockVaultClient<'static>, DeFindexSimpleClient<'static>) {
+    let vault = MockVaultClient::new(&setup.e, &setup.e.register(MockVault, (vec![&setup.e, native.clone()], Address::generate(&setup.e))));
+    let config = ZapConfig {
+        admin: setup.admin.clone(),
+        vault: vault.address.clone(),
+        router: setup.router.address.clone(),
+        underlying_asset: native.clone(),
+        denominator: BPS_DENOMINATOR as u32,
+    };
+    let contract = DeFindexSimpleClient::new(&setup.e, &setup.e.register(DeFindexSimple, (config, true)));
+    (vault, contract)
+}
+
+#[test]
+fn deposit_native_swaps_xlm_into_the_underlying() {
+    let setup = Setup::new();
+    let native = native_asset(&setup, &setup.user, 1_000);
+    setup.router.set_rate(&native, &setup.underlying, &2, &1);
+
+    let deposited = setup.contract.deposit_native(&setup.user, &100, &200, &None);
+
+    assert_eq!(deposited, 200);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+    assert_eq!(setup.balance(&native, &setup.user), 900);
+    assert_eq!(setup.router.swap_calls(), 1);
+}
+
+#[test]
+fn deposit_native_checks_the_native_balance() {
+    let setup = Setup::new();
+    native_asset(&setup, &setup.user, 50);
+
+    let result = setup.contract.try_deposit_native(&setup.user, &100, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientBalance)));
+}
+
+#[test]
+fn xlm_vault_deposit_skips_the_swap_and_auto_invests() {
+    let setup = Setup::new();
+    let native = native_asset(&setup, &setup.user, 1_000);
+    let (vault, contract) = xlm_vault(&setup, &native);
+    vault.set_rebalance_manager(&contract.address);
+    contract.set_auto_invest_threshold(&setup.admin, &300);
+
+    let deposited = contract.deposit_native(&setup.user, &300, &300, &None);
+
+    assert_eq!(deposited, 300);
+    assert_eq!(vault.balance(&setup.user), 300);
+    assert_eq!(setup.balance(&native, &setup.user), 700);
+    assert_eq!(setup.router.swap_calls(), 0);
+    assert_eq!(vault.invest_calls(), 1);
+}
+
+#[test]
+fn xlm_vault_deposit_goes_through_deposit_step() {
+    let setup = Setup::new();
+    let native = native_asset(&setup, &setup.user, 1_000);
+    let (vault, contract) = xlm_vault(&setup, &native);
+
+    let result = contract.try_deposit_native(&setup.user, &300, &301, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+
+    vault.set_fail_deposit(&true);
+    let result = contract.try_deposit_native(&setup.user, &300, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::DepositStepFailed)));
+}
+
+#[test]
+fn xlm_vault_deposit_enforces_the_deadline() {
+    let setup = Setup::new();
+    let native = native_asset(&setup, &setup.user, 1_000);
+    let (vault, contract) = xlm_vault(&setup, &native);
+    setup.e.ledger().set_timestamp(1_000);
+
+    let result = contract.try_deposit_native(&setup.user, &300, &0, &Some(999));
+
+    assert_eq!(result, Err(Ok(DeFindexError::DeadlineExpired)));
+    assert_eq!(vault.balance(&setup.user), 0);
+    assert_eq!(setup.balance(&native, &setup.user), 1_000);
+}
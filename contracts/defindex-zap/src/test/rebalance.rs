@@ -0,0 +1,61 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use workshop_mocks::{MockVault, MockVaultClient};
+
+use super::Setup;
+use crate::error::DeFindexError;
+
+/// Zaps 100 token_in into the setup's vault (200 shares) and allow-lists it together with a
+/// second vault whose underlying is `token_in`
+fn two_vaults() -> (Setup<'static>, MockVaultClient<'static>) {
+    let setup = Setup::new();
+    let other = MockVaultClient::new(&setup.e, &setup.e.register(MockVault, (vec![&setup.e, setup.token_in.clone()], Address::generate(&setup.e))));
+    setup.contract.set_rebalance_vault(&setup.admin, &setup.vault.address, &true);
+    setup.contract.set_rebalance_vault(&setup.admin, &other.address, &true);
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    (setup, other)
+}
+
+#[test]
+fn rebalance_bridges_between_vaults_with_different_underlyings() {
+    let (setup, other) = two_vaults();
+    let bridge_path = vec![&setup.e, setup.underlying.clone(), setup.token_in.clone()];
+
+    // 200 underlying bridged into 100 token_in, minting 100 shares in the empty vault
+    let shares = setup.contract.rebalance(&setup.user, &setup.vault.address, &other.address, &200, &bridge_path, &100, &None);
+
+    assert_eq!(shares, 100);
+    assert_eq!(setup.vault.balance(&setup.user), 0);
+    assert_eq!(other.balance(&setup.user), 100);
+    assert_eq!(setup.balance(&setup.token_in, &other.address), 100);
+}
+
+#[test]
+fn rebalance_enforces_min_shares() {
+    let (setup, other) = two_vaults();
+    let bridge_path = vec![&setup.e, setup.underlying.clone(), setup.token_in.clone()];
+
+    let result = setup.contract.try_rebalance(&setup.user, &setup.vault.address, &other.address, &200, &bridge_path, &101, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientShares)));
+}
+
+#[test]
+fn rebalance_requires_allow_listed_vaults() {
+    let (setup, other) = two_vaults();
+    setup.contract.set_rebalance_vault(&setup.admin, &other.address, &false);
+
+    let result = setup.contract.try_rebalance(&setup.user, &setup.vault.address, &other.address, &200, &vec![&setup.e], &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::Unauthorized)));
+}
+
+#[test]
+fn rebalance_rejects_multi_asset_vaults() {
+    let (setup, _) = two_vaults();
+    let assets = vec![&setup.e, setup.underlying.clone(), setup.token_in.clone()];
+    let multi = MockVaultClient::new(&setup.e, &setup.e.register(MockVault, (assets, Address::generate(&setup.e))));
+    setup.contract.set_rebalance_vault(&setup.admin, &multi.address, &true);
+
+    let result = setup.contract.try_rebalance(&setup.user, &setup.vault.address, &multi.address, &200, &vec![&setup.e], &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InvalidArgument)));
+    let result = setup.contract.try_rebalance(&setup.user, &multi.address, &setup.vault.address, &200, &vec![&setup.e], &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InvalidArgument)));
+}
@@ -0,0 +1,41 @@
+use soroban_sdk::{testutils::Events, Map, Symbol, TryFromVal};
+
+use super::{Setup, FUNDS};
+use crate::SwapReceipt;
+
+/// Receipt of the last `SwapExecuted` event published by the contract
+pub fn swap_executed_receipt(setup: &Setup) -> SwapReceipt {
+    let (_, _, data) = setup
+        .e
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            let name = topics.first().and_then(|topic| Symbol::try_from_val(&setup.e, &topic).ok());
+            *contract == setup.contract.address && name == Some(Symbol::new(&setup.e, "swap_executed"))
+        })
+        .last()
+        .expect("no SwapExecuted event");
+    let fields = Map::<Symbol, SwapReceipt>::try_from_val(&setup.e, &data).unwrap();
+    fields.get(Symbol::new(&setup.e, "receipt")).unwrap()
+}
+
+#[test]
+fn zap_swap_receipt_fields() {
+    let setup = Setup::new();
+
+    let deposited = setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+
+    let expected = SwapReceipt {
+        token_in: setup.token_in.clone(),
+        token_out: setup.underlying.clone(),
+        amount_in: 100,
+        amount_out: 200,
+        path_len: 2,
+    };
+    assert_eq!(deposited, 200);
+    assert_eq!(swap_executed_receipt(&setup), expected);
+    assert_eq!(setup.vault.balance(&setup.user), 200);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100);
+    assert_eq!(setup.router.swap_calls(), 1);
+}
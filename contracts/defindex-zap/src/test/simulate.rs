@@ -0,0 +1,45 @@
+use soroban_sdk::{token, vec};
+
+use super::Setup;
+use crate::{error::DeFindexError, ZapSimulation, PRICE_SCALE};
+
+#[test]
+fn simulate_zap_reports_deterministic_figures() {
+    let setup = Setup::new();
+
+    let simulation = setup.contract.simulate_zap(&setup.token_in, &100);
+
+    assert_eq!(
+        simulation,
+        ZapSimulation {
+            path: vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()],
+            expected_underlying: 200,
+            expected_shares: 200,
+            effective_price: 2 * PRICE_SCALE,
+        }
+    );
+}
+
+#[test]
+fn simulate_zap_matches_the_executed_deposit() {
+    let setup = Setup::new();
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    // Vault gains: 200 shares now back 400 underlying
+    token::StellarAssetClient::new(&setup.e, &setup.underlying).mint(&setup.vault.address, &200);
+
+    let simulation = setup.contract.simulate_zap(&setup.token_in, &100);
+    assert_eq!(simulation.expected_underlying, 200);
+    assert_eq!(simulation.expected_shares, 100);
+
+    // Nothing was executed by the simulation
+    assert_eq!(setup.router.swap_calls(), 1);
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &simulation.expected_shares, &100);
+    assert_eq!(setup.vault.balance(&setup.user), 300);
+}
+
+#[test]
+fn simulate_zap_rejects_a_zero_amount() {
+    let setup = Setup::new();
+
+    assert_eq!(setup.contract.try_simulate_zap(&setup.token_in, &0), Err(Ok(DeFindexError::InvalidArgument)));
+}
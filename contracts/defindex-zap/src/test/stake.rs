@@ -0,0 +1,37 @@
+use workshop_mocks::{MockStaking, MockStakingClient};
+
+use super::{Setup, FUNDS};
+use crate::error::DeFindexError;
+
+fn staking(setup: &Setup) -> MockStakingClient<'static> {
+    MockStakingClient::new(&setup.e, &setup.e.register(MockStaking, (setup.vault.address.clone(),)))
+}
+
+#[test]
+fn deposit_and_stake_stakes_the_minted_shares() {
+    let setup = Setup::new();
+    let staking = staking(&setup);
+    setup.contract.set_staking_contract(&setup.admin, &staking.address, &true);
+
+    let staked = setup.contract.deposit_and_stake(&setup.user, &setup.token_in, &100, &staking.address, &200, &None);
+
+    assert_eq!(staked, 200);
+    assert_eq!(staking.staked(&setup.user), 200);
+    assert_eq!(setup.vault.balance(&setup.user), 0);
+    assert_eq!(setup.vault.balance(&staking.address), 200);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100);
+}
+
+#[test]
+fn deposit_and_stake_requires_an_allow_listed_staking_contract() {
+    let setup = Setup::new();
+    let staking = staking(&setup);
+
+    let result = setup.contract.try_deposit_and_stake(&setup.user, &setup.token_in, &100, &staking.address, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::Unauthorized)));
+
+    setup.contract.set_staking_contract(&setup.admin, &staking.address, &true);
+    setup.contract.set_staking_contract(&setup.admin, &staking.address, &false);
+    let result = setup.contract.try_deposit_and_stake(&setup.user, &setup.token_in, &100, &staking.address, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::Unauthorized)));
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{testutils::Events, vec, Address, Map, Symbol, TryFromVal};
+
+use super::Setup;
+use crate::{error::DeFindexError, events::UnderlyingAssetUpdated};
+
+#[test]
+fn zaps_target_the_new_underlying() {
+    let setup = Setup::new();
+    let new_underlying = setup.e.register_stellar_asset_contract_v2(setup.admin.clone()).address();
+    setup.router.set_rate(&setup.token_in, &new_underlying, &3, &1);
+
+    setup.contract.set_underlying_asset(&setup.admin, &new_underlying);
+
+    // Events only cover the last invocation: read them first
+    let (_, topics, data) = setup.e.events().all().last().unwrap();
+    let name = Symbol::try_from_val(&setup.e, &topics.first().unwrap()).unwrap();
+    assert_eq!(name, Symbol::new(&setup.e, "underlying_asset_updated"));
+    let fields = Map::<Symbol, Address>::try_from_val(&setup.e, &data).unwrap();
+    let event = UnderlyingAssetUpdated {
+        old_underlying: fields.get(Symbol::new(&setup.e, "old_underlying")).unwrap(),
+        new_underlying: fields.get(Symbol::new(&setup.e, "new_underlying")).unwrap(),
+    };
+    assert_eq!(event, UnderlyingAssetUpdated { old_underlying: setup.underlying.clone(), new_underlying: new_underlying.clone() });
+
+    assert_eq!(setup.contract.get_config().underlying_asset, new_underlying);
+    let simulation = setup.contract.simulate_zap(&setup.token_in, &100);
+    assert_eq!(simulation.path, vec![&setup.e, setup.token_in.clone(), new_underlying.clone()]);
+    assert_eq!(simulation.expected_underlying, 300);
+
+    // A route into the old underlying no longer lands on the vault's asset
+    let old_path = vec![&setup.e, setup.token_in.clone(), setup.underlying.clone()];
+    let result = setup.contract.try_deposit_with_path(&setup.user, &old_path, &100, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InvalidArgument)));
+}
+
+#[test]
+fn set_underlying_asset_is_admin_only() {
+    let setup = Setup::new();
+
+    let result = setup.contract.try_set_underlying_asset(&setup.user, &setup.token_in);
+    assert_eq!(result, Err(Ok(DeFindexError::Unauthorized)));
+    assert_eq!(setup.contract.get_config().underlying_asset, setup.underlying);
+}
@@ -0,0 +1,123 @@
+use soroban_sdk::{testutils::Address as _, token, vec, Address};
+use workshop_mocks::{MockVault, MockVaultClient};
+
+use super::{receipt::swap_executed_receipt, Setup, FUNDS};
+use crate::{error::DeFindexError, DeFindexSimple, DeFindexSimpleClient, SwapReceipt, ZapConfig};
+
+/// Zaps 100 token_in in, for 200 underlying and 200 shares
+fn deposited() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.contract.deposit(&setup.user, &setup.token_in, &100, &None, &false, &0, &100);
+    setup
+}
+
+#[test]
+fn withdraw_pct_swaps_through_swap_step() {
+    let setup = deposited();
+
+    // Half of the position: 100 underlying back into 50 token_in
+    let total_out = setup.contract.withdraw_pct(&setup.user, &5_000, &setup.token_in, &50, &None);
+
+    // Events only cover the last invocation: read them first
+    assert_eq!(
+        swap_executed_receipt(&setup),
+        SwapReceipt {
+            token_in: setup.underlying.clone(),
+            token_out: setup.token_in.clone(),
+            amount_in: 100,
+            amount_out: 50,
+            path_len: 2,
+        }
+    );
+    assert_eq!(total_out, 50);
+    assert_eq!(setup.vault.balance(&setup.user), 100);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100 + 50);
+}
+
+#[test]
+fn withdraw_pct_respects_the_liquidity_guard() {
+    let setup = deposited();
+    setup.contract.set_liquidity_guard(&setup.admin, &100);
+    // 1% of a 1_000 underlying reserve is less than the 100 redeemed
+    setup.router.set_reserves(&setup.underlying, &setup.token_in, &1_000, &500);
+
+    let result = setup.contract.try_withdraw_pct(&setup.user, &5_000, &setup.token_in, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InsufficientLiquidity)));
+}
+
+#[test]
+fn withdraw_pct_reports_router_failures() {
+    let setup = deposited();
+    setup.router.set_fail(&true);
+
+    let result = setup.contract.try_withdraw_pct(&setup.user, &5_000, &setup.token_in, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::SwapStepFailed)));
+}
+
+#[test]
+fn withdraw_pct_redeems_a_quarter_of_the_shares() {
+    let setup = deposited();
+
+    // 2_500 bps of 200 shares: 50 shares, 50 underlying, 25 token_in
+    let total_out = setup.contract.withdraw_pct(&setup.user, &2_500, &setup.token_in, &25, &None);
+
+    assert_eq!(total_out, 25);
+    assert_eq!(setup.vault.balance(&setup.user), 150);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS - 100 + 25);
+}
+
+#[test]
+fn withdraw_pct_validates_bps() {
+    let setup = deposited();
+
+    let result = setup.contract.try_withdraw_pct(&setup.user, &10_001, &setup.token_in, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InvalidArgument)));
+    let result = setup.contract.try_withdraw_pct(&setup.user, &0, &setup.token_in, &0, &None);
+    assert_eq!(result, Err(Ok(DeFindexError::InvalidArgument)));
+
+    // The full position is allowed
+    setup.contract.withdraw_pct(&setup.user, &10_000, &setup.token_in, &100, &None);
+    assert_eq!(setup.vault.balance(&setup.user), 0);
+}
+
+#[test]
+fn withdrawing_into_the_underlying_skips_the_router() {
+    let setup = deposited();
+
+    let total_out = setup.contract.withdraw_pct(&setup.user, &5_000, &setup.underlying, &100, &None);
+
+    assert_eq!(total_out, 100);
+    // Only the zap-in swap reached the router
+    assert_eq!(setup.router.swap_calls(), 1);
+    assert_eq!(setup.balance(&setup.underlying, &setup.user), 100);
+    assert_eq!(setup.vault.balance(&setup.user), 100);
+}
+
+#[test]
+fn withdraw_pct_swaps_every_asset_of_a_multi_asset_vault() {
+    let setup = Setup::new();
+    let token_c = setup.e.register_stellar_asset_contract_v2(setup.admin.clone()).address();
+    let assets = vec![&setup.e, setup.underlying.clone(), token_c.clone()];
+    let vault = MockVaultClient::new(&setup.e, &setup.e.register(MockVault, (assets, Address::generate(&setup.e))));
+    let config = ZapConfig { vault: vault.address.clone(), ..setup.contract.get_config() };
+    let contract = DeFindexSimpleClient::new(&setup.e, &setup.e.register(DeFindexSimple, (config, true)));
+
+    // 400 underlying + 300 token_c for 400 shares
+    token::StellarAssetClient::new(&setup.e, &setup.underlying).mint(&setup.user, &400);
+    token::StellarAssetClient::new(&setup.e, &token_c).mint(&setup.user, &300);
+    vault.deposit(&vec![&setup.e, 400, 300], &vec![&setup.e, 0, 0], &setup.user, &false);
+
+    // underlying -> token_in at 1:2 (the reverse of the setup's rate), token_c -> token_in at 3:1
+    setup.router.set_rate(&token_c, &setup.token_in, &1, &3);
+    token::StellarAssetClient::new(&setup.e, &setup.token_in).mint(&setup.router.address, &1_000);
+
+    // Half the shares: 200 underlying into 100 token_in, 150 token_c into 50 token_in
+    let total_out = contract.withdraw_pct(&setup.user, &5_000, &setup.token_in, &150, &None);
+
+    assert_eq!(total_out, 150);
+    assert_eq!(setup.router.swap_calls(), 2);
+    assert_eq!(vault.balance(&setup.user), 200);
+    assert_eq!(setup.balance(&setup.token_in, &setup.user), FUNDS + 150);
+    assert_eq!(setup.balance(&setup.underlying, &setup.user), 0);
+    assert_eq!(setup.balance(&token_c, &setup.user), 0);
+}
@@ -1,4 +1,5 @@
 use soroban_sdk::contracterror;
+use workshop_common::timelock::TimelockError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -17,5 +18,27 @@ pub enum SoroswapError {
     /// Invalid parameters
     InvalidArgument = 6,
 
-    NegativeNotAllowed= 7
+    NegativeNotAllowed= 7,
+    /// The (token_in, token_out) pair is not whitelisted
+    PairNotAllowed = 8,
+    /// Timelocked admin operation was not scheduled
+    OperationNotScheduled = 9,
+    /// Timelocked admin operation was scheduled but its delay has not elapsed yet
+    TimelockNotExpired = 10,
+    /// Swap deadline has already passed
+    DeadlineExpired = 11,
+    /// Token transfer delivered a different amount than requested
+    TransferMismatch = 12,
+    /// The caller's authorization expired before the swap ran
+    AuthExpired = 13,
+}
+
+impl From<TimelockError> for SoroswapError {
+    fn from(err: TimelockError) -> Self {
+        match err {
+            TimelockError::NotScheduled => SoroswapError::OperationNotScheduled,
+            TimelockError::NotExpired => SoroswapError::TimelockNotExpired,
+            TimelockError::TooEarly | TimelockError::DelayTooLong => SoroswapError::InvalidArgument,
+        }
+    }
 }
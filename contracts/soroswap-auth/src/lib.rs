@@ -25,19 +25,36 @@
 //! 3. Router can now transfer tokens from user to pair
 
 use soroban_sdk::{
-    Address, Env, IntoVal, Symbol, Val, Vec, auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation}, contract, contractimpl, token, vec
+    Address, BytesN, Env, IntoVal, Symbol, Val, Vec, auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation}, contract, contractimpl, vec
 };
 
+mod events;
+// The generated client exceeds clippy's argument limit in test builds
+#[allow(clippy::too_many_arguments)]
 mod soroswap_router;
 mod storage;
 mod error;
+#[cfg(test)]
+mod test;
 
+use events::SwapExecuted;
+pub use workshop_common::models::SwapReceipt;
 use soroswap_router::SoroswapRouterClient;
 use storage::{
     extend_instance_ttl, get_soroswap_router_address, set_soroswap_router_address,
+    get_admin, set_admin, is_allowed_pair, is_pair_whitelist_enabled, set_allowed_pair,
+    set_pair_whitelist_enabled,
 };
+use workshop_common::time::{ensure_not_expired, resolve_deadline};
+use workshop_common::timelock::{self, check_timelock, op_hash};
+use workshop_common::token::safe_transfer;
 use error::SoroswapError;
 
+/// Fee charged by every Soroswap pair, in basis points (0.3%)
+///
+/// Soroswap has a single fee tier, so this is the same for every pair.
+pub const PAIR_FEE_BPS: u32 = 30;
+
 /// Validates that the amount is non-negative
 ///
 /// Prevents arithmetic issues and invalid swap amounts
@@ -49,15 +66,42 @@ pub fn check_nonnegative_amount(amount: i128) -> Result<(), SoroswapError> {
     }
 }
 
+/// Builds the auth entry for a `token.transfer(from, to, amount)` made by the next contract called
+///
+/// The entry is always a leaf (no nested `sub_invocations`): in the Soroswap flows the router's
+/// `token.transfer` is the only call that needs this contract's authorization. The pair then
+/// pays out as itself, which the runtime authorizes without any entry from us.
+fn transfer_auth_entry(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128) -> InvokerContractAuthEntry {
+    InvokerContractAuthEntry::Contract(SubContractInvocation {
+        context: ContractContext {
+            contract: token.clone(),
+            fn_name: Symbol::new(e, "transfer"),
+            args: vec![e, from.into_val(e), to.into_val(e), amount.into_val(e)],
+        },
+        sub_invocations: vec![e],
+    })
+}
+
+/// Verifies that `admin` signed the transaction and is the stored admin
+fn check_admin(e: &Env, admin: &Address) -> Result<(), SoroswapError> {
+    admin.require_auth();
+    if *admin != get_admin(e) {
+        return Err(SoroswapError::Unauthorized);
+    }
+    Ok(())
+}
+
 #[contract]
 struct SoroswapAuth;
 
 #[contractimpl]
 impl SoroswapAuth {
-    /// Initialize the contract with the Soroswap Router address
+    /// Initialize the contract with the admin and the Soroswap Router address
     ///
-    /// This address is stored and used for all subsequent swap operations
-    pub fn __constructor(e: Env, router_address: Address) {
+    /// The router address is stored and used for all subsequent swap operations.
+    /// The admin manages the pair whitelist.
+    pub fn __constructor(e: Env, admin: Address, router_address: Address) {
+        set_admin(&e, admin);
         set_soroswap_router_address(&e, router_address);
     }
 
@@ -90,19 +134,38 @@ impl SoroswapAuth {
     /// - `token_in`: Token being sold
     /// - `token_out`: Token being purchased
     /// - `amount`: Amount of `token_in` to swap
+    /// - `amount_out_min`: Minimum amount of `token_out` to receive, otherwise the swap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    /// - `auth_expiry`: Unix timestamp after which the caller's signature is no longer accepted.
+    ///   Part of the signed arguments, so it bounds signature freshness independently of a
+    ///   longer `deadline`
     ///
     /// ## Returns:
-    /// Amount of `token_out` received from the swap
-    pub fn swap(e: Env, caller: Address, token_in: Address, token_out: Address, amount: i128) -> Result<i128, SoroswapError> {
+    /// `SwapReceipt` of the swap (`amount_out` is the amount of `token_out` received), also
+    /// emitted as `SwapExecuted`
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap(e: Env, caller: Address, token_in: Address, token_out: Address, amount: i128, amount_out_min: i128, deadline: Option<u64>, auth_expiry: u64) -> Result<SwapReceipt, SoroswapError> {
         // Verify the caller has signed this transaction
         caller.require_auth();
         check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(amount_out_min)?;
         extend_instance_ttl(&e);
 
+        // Signature freshness and execution window are checked separately
+        ensure_not_expired(&e, auth_expiry, SoroswapError::AuthExpired)?;
+
+        // Fail fast before taking custody; the router enforces the same deadline
+        let deadline = resolve_deadline(&e, deadline);
+        ensure_not_expired(&e, deadline, SoroswapError::DeadlineExpired)?;
+
+        // In a constrained deployment, only vetted pairs can be swapped
+        if is_pair_whitelist_enabled(&e) && !is_allowed_pair(&e, &token_in, &token_out) {
+            return Err(SoroswapError::PairNotAllowed);
+        }
+
         // Transfer tokens from the user to this contract (contract takes custody)
         // The user's signature authorizes this transfer
-        let token_client = token::Client::new(&e, &token_in);
-        token_client.transfer(&caller, e.current_contract_address(), &amount);
+        safe_transfer(&e, &token_in, &caller, &e.current_contract_address(), amount, SoroswapError::TransferMismatch)?;
 
         // Get the stored Soroswap Router address and create client
         let soroswap_router_address = get_soroswap_router_address(&e);
@@ -116,49 +179,195 @@ impl SoroswapAuth {
         path.push_back(token_in.clone());
         path.push_back(token_out.clone());
 
-        // Prepare the arguments for the token transfer that will happen inside the router
-        // This represents: token.transfer(from=caller, to=pair, amount=amount)
-        let mut transfer_args: Vec<Val> = vec![&e];
-        transfer_args.push_back(caller.into_val(&e));         // From: original caller
-        transfer_args.push_back(pair_address.into_val(&e));   // To: liquidity pair
-        transfer_args.push_back(amount.into_val(&e));         // Amount to transfer
-
         // CRITICAL: Create authorization context for the sub-contract invocation
         // This tells the Soroban runtime: "When the router calls token.transfer() with these
         // exact arguments, I (the current contract) authorize it on behalf of my caller"
         //
-        // The SubContractInvocation specifies:
+        // The entry built by `transfer_auth_entry` specifies:
         // - Which contract will be called (token_in)
         // - Which function will be invoked ("transfer")
-        // - What arguments will be passed (transfer_args)
+        // - What arguments will be passed: token.transfer(from=caller, to=pair, amount=amount)
         //
         // This creates a secure authorization chain: caller → this contract → router → token
         e.authorize_as_current_contract(vec![
             &e,
-            InvokerContractAuthEntry::Contract(SubContractInvocation {
-                context: ContractContext {
-                    contract: token_in.clone(),                // The token contract being authorized
-                    fn_name: Symbol::new(&e, "transfer"),      // The function being authorized
-                    args: transfer_args.clone(),               // The exact arguments allowed
-                },
-                sub_invocations: vec![&e],                     // No further nested invocations
-            }),
+            transfer_auth_entry(&e, &token_in, &caller, &pair_address, amount),
         ]);
 
         // Execute the swap through the router
         // The authorization context above allows the router to transfer tokens from the caller
         // to the pair, even though the caller didn't directly authorize the router
         let swap_result = soroswap_router_client.swap_exact_tokens_for_tokens(
-            &amount,     // Exact amount to swap
-            &0,          // Minimum amount out (0 for simplicity; use slippage calculation in production)
-            &path,       // Swap route
-            &caller,     // Recipient of output tokens (tokens go back to the original caller)
-            &u64::MAX,   // Deadline (max for simplicity; use actual timestamp in production)
+            &amount,         // Exact amount to swap
+            &amount_out_min, // Slippage protection
+            &path,           // Swap route
+            &caller,         // Recipient of output tokens (tokens go back to the original caller)
+            &deadline,       // Deadline (caller-provided or now + DEFAULT_DEADLINE_WINDOW)
         );
 
-        // Return the amount of token_out received
-        let total_swapped_amount = swap_result.last().unwrap();
+        // `amount_out` is the amount of token_out received
+        let receipt = SwapReceipt::new(&path, amount, swap_result.last().unwrap());
+        SwapExecuted { receipt: receipt.clone() }.publish(&e);
+
+        Ok(receipt)
+    }
+
+    /// Remove liquidity from a Soroswap pair with explicit authorization context
+    ///
+    /// Same custody pattern as `swap`, applied to the LP token: the contract takes the
+    /// caller's LP tokens, calls the router with itself as `to`, and pre-authorizes the
+    /// router's `pair.transfer(this contract, pair, liquidity)` sub-invocation. The withdrawn
+    /// tokens are then forwarded to the caller.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The liquidity provider (must sign the transaction)
+    /// - `token_a`, `token_b`: Tokens of the pair
+    /// - `liquidity`: Amount of LP tokens to burn
+    /// - `min_a`, `min_b`: Minimum amounts of `token_a` / `token_b` to receive
+    /// - `deadline`: Unix timestamp after which the removal reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `(amount_a, amount_b)` withdrawn
+    #[allow(clippy::too_many_arguments)]
+    pub fn remove_liquidity(
+        e: Env,
+        caller: Address,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        min_a: i128,
+        min_b: i128,
+        deadline: Option<u64>,
+    ) -> Result<(i128, i128), SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(min_a)?;
+        check_nonnegative_amount(min_b)?;
+        extend_instance_ttl(&e);
+
+        let deadline = resolve_deadline(&e, deadline);
+        ensure_not_expired(&e, deadline, SoroswapError::DeadlineExpired)?;
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        // The pair contract is also the LP token
+        let pair_address = soroswap_router_client.router_pair_for(&token_a, &token_b);
+
+        // Take custody of the caller's LP tokens
+        safe_transfer(&e, &pair_address, &caller, &e.current_contract_address(), liquidity, SoroswapError::TransferMismatch)?;
+
+        // The router moves the LP tokens from `to` (this contract) into the pair before burning
+        e.authorize_as_current_contract(vec![
+            &e,
+            transfer_auth_entry(&e, &pair_address, &e.current_contract_address(), &pair_address, liquidity),
+        ]);
+
+        let (amount_a, amount_b) = soroswap_router_client.remove_liquidity(
+            &token_a,
+            &token_b,
+            &liquidity,
+            &min_a,                        // Slippage protection for token_a
+            &min_b,                        // Slippage protection for token_b
+            &e.current_contract_address(), // Withdrawn tokens land here, then go to the caller
+            &deadline,
+        );
+
+        safe_transfer(&e, &token_a, &e.current_contract_address(), &caller, amount_a, SoroswapError::TransferMismatch)?;
+        safe_transfer(&e, &token_b, &e.current_contract_address(), &caller, amount_b, SoroswapError::TransferMismatch)?;
+
+        Ok((amount_a, amount_b))
+    }
+
+    /// Enable or disable the pair whitelist (admin only)
+    ///
+    /// While enabled, `swap` only accepts `(token_in, token_out)` pairs added with `set_pair_allowed`.
+    pub fn set_pair_whitelist(e: Env, admin: Address, enabled: bool) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_pair_whitelist", vec![&e, enabled.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        set_pair_whitelist_enabled(&e, enabled);
+        Ok(())
+    }
+
+    /// Add or remove a `(token_in, token_out)` pair from the whitelist (admin only)
+    ///
+    /// Pairs are directional: allowing `A -> B` does not allow `B -> A`.
+    pub fn set_pair_allowed(e: Env, admin: Address, token_in: Address, token_out: Address, allowed: bool) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_pair_allowed", vec![&e, token_in.into_val(&e), token_out.into_val(&e), allowed.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        set_allowed_pair(&e, &token_in, &token_out, allowed);
+        Ok(())
+    }
+
+    /// Returns the swap fee of the `token_in`/`token_out` pair, in basis points
+    ///
+    /// Lets clients compute the true cost of a swap. Soroswap pairs don't expose their fee,
+    /// and every pair charges `PAIR_FEE_BPS`, so this only checks that the pair exists.
+    pub fn get_pair_fee(e: Env, token_in: Address, token_out: Address) -> u32 {
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        // Reverts if the pair doesn't exist
+        soroswap_router_client.get_reserves(&soroswap_router_client.get_factory(), &token_in, &token_out);
+        PAIR_FEE_BPS
+    }
+
+    /// Returns the Soroswap pair address for `token_in`/`token_out`
+    ///
+    /// Read-only helper for clients that need to approve or inspect the pool directly.
+    pub fn get_pair(e: Env, token_in: Address, token_out: Address) -> Address {
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        soroswap_router_client.router_pair_for(&token_in, &token_out)
+    }
+
+    /// Schedule a timelocked admin operation (admin only)
+    ///
+    /// While the timelock delay is non-zero, every admin setter only runs once its
+    /// `op_hash` (see `get_op_hash`) has been scheduled and `execute_after` has passed.
+    /// `execute_after` must be at least `now + timelock delay`.
+    pub fn schedule(e: Env, admin: Address, op_hash: BytesN<32>, execute_after: u64) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::schedule(&e, &op_hash, execute_after)?;
+        Ok(())
+    }
+
+    /// Cancel a scheduled admin operation (admin only)
+    pub fn cancel(e: Env, admin: Address, op_hash: BytesN<32>) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::cancel(&e, &op_hash);
+        Ok(())
+    }
+
+    /// Set the timelock delay in seconds (admin only, 0 = disabled)
+    ///
+    /// Changing the delay is itself timelocked once a delay is in place, so a compromised
+    /// admin can't disable it instantly. At most `MAX_TIMELOCK_DELAY` (30 days).
+    pub fn set_timelock_delay(e: Env, admin: Address, delay: u64) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::set_delay(&e, delay)?;
+        Ok(())
+    }
+
+    /// Returns the current timelock delay in seconds (0 = disabled)
+    pub fn get_timelock_delay(e: Env) -> u64 {
+        timelock::get_delay(&e)
+    }
 
-        Ok(total_swapped_amount)
+    /// Returns the hash to `schedule` for calling `fn_name` with `args` (without `admin`)
+    pub fn get_op_hash(e: Env, fn_name: Symbol, args: Vec<Val>) -> BytesN<32> {
+        op_hash(&e, fn_name, args)
     }
 }
@@ -4,7 +4,10 @@ use soroban_sdk::{contracttype, Address, Env};
 #[contracttype]
 
 enum DataKey {
+    Admin,
     SoroswapRouterAddress,
+    PairWhitelistEnabled,
+    AllowedPair(Address, Address),
 }
 
 #[allow(dead_code)]
@@ -13,6 +16,10 @@ const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 #[allow(dead_code)]
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 pub fn extend_instance_ttl(e: &Env) {
     e.storage()
@@ -20,6 +27,20 @@ pub fn extend_instance_ttl(e: &Env) {
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
+// Admin
+pub fn set_admin(e: &Env, address: Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Admin, &address);
+}
+
+pub fn get_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap()
+}
+
 // Soroswap Router Address
 pub fn set_soroswap_router_address(e: &Env, address: Address) {
     e.storage()
@@ -33,3 +54,35 @@ pub fn get_soroswap_router_address(e: &Env) -> Address {
         .get(&DataKey::SoroswapRouterAddress)
         .unwrap()
 }
+
+// Pair whitelist
+pub fn set_pair_whitelist_enabled(e: &Env, enabled: bool) {
+    e.storage()
+        .instance()
+        .set(&DataKey::PairWhitelistEnabled, &enabled);
+}
+
+pub fn is_pair_whitelist_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::PairWhitelistEnabled)
+        .unwrap_or(false)
+}
+
+pub fn set_allowed_pair(e: &Env, token_in: &Address, token_out: &Address, allowed: bool) {
+    let key = DataKey::AllowedPair(token_in.clone(), token_out.clone());
+    if allowed {
+        e.storage().persistent().set(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_allowed_pair(e: &Env, token_in: &Address, token_out: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::AllowedPair(token_in.clone(), token_out.clone()))
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::testutils::Ledger;
+
+use super::Setup;
+use crate::error::SoroswapError;
+
+#[test]
+fn expired_deadline_is_rejected() {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &Some(999), &u64::MAX);
+    assert_eq!(result, Err(Ok(SoroswapError::DeadlineExpired)));
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &Some(1_000), &u64::MAX);
+    assert_eq!(receipt.amount_out, 200);
+}
+
+#[test]
+fn insufficient_output_reverts() {
+    let setup = Setup::new();
+
+    // The router pays 200 for 100
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &201, &None, &u64::MAX);
+    assert!(result.is_err());
+    assert_eq!(setup.router.swap_calls(), 0);
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &200, &None, &u64::MAX);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+}
+
+#[test]
+fn auth_expiry_is_checked_independently_of_the_deadline() {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+
+    // Stale signature, open execution window
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &Some(5_000), &999);
+    assert_eq!(result, Err(Ok(SoroswapError::AuthExpired)));
+
+    // Fresh signature, closed execution window
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &Some(999), &5_000);
+    assert_eq!(result, Err(Ok(SoroswapError::DeadlineExpired)));
+
+    // Both still open: a short-lived signature can back a longer deadline
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &Some(5_000), &1_000);
+    assert_eq!(receipt.amount_out, 200);
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    token, Address, IntoVal, Val, Vec,
+};
+
+use super::{Setup, FUNDS};
+
+/// Registers an LP token as the `token_a`/`token_b` pair, holding `liquidity` for the user,
+/// with the router funded to pay the withdrawal out
+fn with_lp(setup: &Setup, liquidity: i128) -> Address {
+    let pair = setup.e.register_stellar_asset_contract_v2(setup.admin.clone()).address();
+    setup.router.set_pair(&setup.token_a, &setup.token_b, &pair);
+    token::StellarAssetClient::new(&setup.e, &pair).mint(&setup.user, &liquidity);
+    token::StellarAssetClient::new(&setup.e, &setup.token_a).mint(&setup.router.address, &liquidity);
+    pair
+}
+
+/// Authorizes only what the user signs: the call and its LP transfer into the contract
+fn mock_caller_auth(setup: &Setup, pair: &Address, liquidity: i128, min_b: i128) {
+    let remove_args: Vec<Val> = (setup.user.clone(), setup.token_a.clone(), setup.token_b.clone(), liquidity, 0i128, min_b, None::<u64>).into_val(&setup.e);
+    let custody_args: Vec<Val> = (setup.user.clone(), setup.contract.address.clone(), liquidity).into_val(&setup.e);
+    setup.e.mock_auths(&[MockAuth {
+        address: &setup.user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "remove_liquidity",
+            args: remove_args,
+            sub_invokes: &[MockAuthInvoke {
+                contract: pair,
+                fn_name: "transfer",
+                args: custody_args,
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+}
+
+#[test]
+fn remove_liquidity_pre_authorizes_the_lp_transfer() {
+    let setup = Setup::new();
+    let pair = with_lp(&setup, 100);
+
+    // The router's pull of the LP tokens from the contract is covered by the contract alone
+    mock_caller_auth(&setup, &pair, 100, 200);
+    let (amount_a, amount_b) = setup.contract.remove_liquidity(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &200, &None);
+
+    assert_eq!((amount_a, amount_b), (100, 200));
+    assert_eq!(setup.balance(&pair, &setup.user), 0);
+    assert_eq!(setup.balance(&pair, &pair), 100);
+    assert_eq!(setup.balance(&setup.token_a, &setup.user), FUNDS + 100);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+    assert_eq!(setup.balance(&setup.token_a, &setup.contract.address), 0);
+    assert_eq!(setup.balance(&setup.token_b, &setup.contract.address), 0);
+}
+
+#[test]
+fn remove_liquidity_enforces_the_minimums() {
+    let setup = Setup::new();
+    let pair = with_lp(&setup, 100);
+
+    mock_caller_auth(&setup, &pair, 100, 201);
+    let result = setup.contract.try_remove_liquidity(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &201, &None);
+
+    assert!(result.is_err());
+    assert_eq!(setup.balance(&pair, &setup.user), 100);
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+use workshop_mocks::{MockRouter, MockRouterClient};
+
+use crate::{SoroswapAuth, SoroswapAuthClient};
+
+mod deadline;
+mod liquidity;
+mod pair;
+mod receipt;
+mod whitelist;
+
+/// Starting balance of `user` in `token_a` and of the router in `token_b`
+pub const FUNDS: i128 = 1_000_000_000;
+
+/// A `SoroswapAuth` in front of a mock router swapping `token_a -> token_b` at 1:2
+pub struct Setup<'a> {
+    pub e: Env,
+    pub admin: Address,
+    pub user: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    pub router: MockRouterClient<'a>,
+    pub contract: SoroswapAuthClient<'a>,
+}
+
+impl Setup<'_> {
+    pub fn new() -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let admin = Address::generate(&e);
+        let user = Address::generate(&e);
+        let token_a = e.register_stellar_asset_contract_v2(admin.clone()).address();
+        let token_b = e.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let router = MockRouterClient::new(&e, &e.register(MockRouter, ()));
+        router.set_rate(&token_a, &token_b, &2, &1);
+        token::StellarAssetClient::new(&e, &token_a).mint(&user, &FUNDS);
+        token::StellarAssetClient::new(&e, &token_b).mint(&router.address, &FUNDS);
+
+        let contract = SoroswapAuthClient::new(&e, &e.register(SoroswapAuth, (admin.clone(), router.address.clone())));
+
+        Setup { e, admin, user, token_a, token_b, router, contract }
+    }
+
+    pub fn balance(&self, token: &Address, id: &Address) -> i128 {
+        token::Client::new(&self.e, token).balance(id)
+    }
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{testutils::Events, Map, Symbol, TryFromVal};
+
+use super::Setup;
+use crate::SwapReceipt;
+
+/// Receipt of the last `SwapExecuted` event published by the contract
+fn swap_executed_receipt(setup: &Setup) -> SwapReceipt {
+    let (_, _, data) = setup
+        .e
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            let name = topics.first().and_then(|topic| Symbol::try_from_val(&setup.e, &topic).ok());
+            *contract == setup.contract.address && name == Some(Symbol::new(&setup.e, "swap_executed"))
+        })
+        .last()
+        .expect("no SwapExecuted event");
+    let fields = Map::<Symbol, SwapReceipt>::try_from_val(&setup.e, &data).unwrap();
+    fields.get(Symbol::new(&setup.e, "receipt")).unwrap()
+}
+
+#[test]
+fn swap_receipt_fields() {
+    let setup = Setup::new();
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &150, &None, &u64::MAX);
+
+    let expected = SwapReceipt {
+        token_in: setup.token_a.clone(),
+        token_out: setup.token_b.clone(),
+        amount_in: 100,
+        amount_out: 200,
+        path_len: 2,
+    };
+    assert_eq!(receipt, expected);
+    assert_eq!(swap_executed_receipt(&setup), expected);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), receipt.amount_out);
+}
@@ -0,0 +1,36 @@
+use super::Setup;
+use crate::error::SoroswapError;
+
+/// Whitelist on, with only `token_a -> token_b` allowed
+fn whitelisted() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.contract.set_pair_whitelist(&setup.admin, &true);
+    setup.contract.set_pair_allowed(&setup.admin, &setup.token_a, &setup.token_b, &true);
+    setup
+}
+
+#[test]
+fn allowed_pair_swaps() {
+    let setup = whitelisted();
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &200, &None, &u64::MAX);
+
+    assert_eq!(receipt.amount_out, 200);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+}
+
+#[test]
+fn disallowed_pair_is_rejected_before_custody() {
+    let setup = whitelisted();
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &None, &u64::MAX);
+
+    // Pairs are directional: the reverse direction was never allowed
+    let result = setup.contract.try_swap(&setup.user, &setup.token_b, &setup.token_a, &100, &0, &None, &u64::MAX);
+    assert_eq!(result, Err(Ok(SoroswapError::PairNotAllowed)));
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+    assert_eq!(setup.router.swap_calls(), 1);
+
+    setup.contract.set_pair_allowed(&setup.admin, &setup.token_a, &setup.token_b, &false);
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &None, &u64::MAX);
+    assert_eq!(result, Err(Ok(SoroswapError::PairNotAllowed)));
+}
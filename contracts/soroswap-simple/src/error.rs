@@ -1,4 +1,6 @@
 use soroban_sdk::contracterror;
+use workshop_common::math::MathError;
+use workshop_common::timelock::TimelockError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -17,5 +19,40 @@ pub enum SoroswapError {
     /// Invalid parameters
     InvalidArgument = 6,
 
-    NegativeNotAllowed= 7
+    NegativeNotAllowed= 7,
+    /// Caller has no balance of the token to swap
+    ZeroBalance = 8,
+    /// Realized price impact exceeds the admin-set maximum
+    ExcessiveSlippage = 9,
+    /// Arithmetic overflow
+    ArithmeticError = 10,
+    /// Timelocked admin operation was not scheduled
+    OperationNotScheduled = 11,
+    /// Timelocked admin operation was scheduled but its delay has not elapsed yet
+    TimelockNotExpired = 12,
+    /// Token transfer delivered a different amount than requested
+    TransferMismatch = 13,
+    /// Swap deadline has already passed
+    DeadlineExpired = 14,
+    /// The contract retained tokens it should only have passed through
+    InvariantViolation = 15,
+}
+
+impl From<MathError> for SoroswapError {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::DivisionByZero => SoroswapError::InvalidArgument,
+            MathError::Overflow => SoroswapError::ArithmeticError,
+        }
+    }
+}
+
+impl From<TimelockError> for SoroswapError {
+    fn from(err: TimelockError) -> Self {
+        match err {
+            TimelockError::NotScheduled => SoroswapError::OperationNotScheduled,
+            TimelockError::NotExpired => SoroswapError::TimelockNotExpired,
+            TimelockError::TooEarly | TimelockError::DelayTooLong => SoroswapError::InvalidArgument,
+        }
+    }
 }
@@ -0,0 +1,10 @@
+use soroban_sdk::contractevent;
+
+use workshop_common::models::SwapReceipt;
+
+/// Emitted for every swap executed through this contract
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapExecuted {
+    pub receipt: SwapReceipt,
+}
@@ -15,19 +15,50 @@
 //! never takes custody of tokens, so no additional auth context is required.
 
 use soroban_sdk::{
-    contract, contractimpl, Address, Env, Vec,
+    contract, contractimpl, token, vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec,
 };
 
+mod events;
+mod models;
+// The generated client exceeds clippy's argument limit in test builds
+#[allow(clippy::too_many_arguments)]
 mod soroswap_router;
 mod storage;
 mod error;
+#[cfg(test)]
+mod test;
 
 use soroswap_router::SoroswapRouterClient;
+use events::SwapExecuted;
+pub use models::SwapJob;
+pub use workshop_common::models::SwapReceipt;
 use storage::{
     extend_instance_ttl, get_soroswap_router_address, set_soroswap_router_address,
+    get_swap_count, get_swap_volume, record_swap,
+    get_admin, set_admin, get_max_impact, set_max_impact, get_denominator, set_denominator,
+    get_pair_slippage, set_pair_slippage, get_max_path_len, set_max_path_len,
 };
+use workshop_common::math::{mul_div, Rounding};
+use workshop_common::path::{select_best, SwapPath};
+use workshop_common::time::{ensure_not_expired, resolve_deadline};
+use workshop_common::timelock::{self, check_timelock, op_hash};
+use workshop_common::token::safe_transfer;
 use error::SoroswapError;
 
+/// Basis points denominator (10_000 bps = 100%)
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Fixed-point scale for exchange rates (7 decimals, like Stellar assets)
+pub const PRICE_SCALE: i128 = 10_000_000;
+
+/// Parts-per-million denominator (1_000_000 ppm = 100%), for sub-basis-point granularity
+pub const PPM_DENOMINATOR: i128 = 1_000_000;
+
+/// Fee charged by every Soroswap pair, in basis points (0.3%)
+///
+/// Soroswap has a single fee tier, so this is the same for every pair.
+pub const PAIR_FEE_BPS: u32 = 30;
+
 /// Validates that the amount is non-negative
 ///
 /// Prevents arithmetic issues and invalid swap amounts
@@ -39,62 +70,539 @@ pub fn check_nonnegative_amount(amount: i128) -> Result<(), SoroswapError> {
     }
 }
 
+/// Verifies that `admin` signed the transaction and is the stored admin
+fn check_admin(e: &Env, admin: &Address) -> Result<(), SoroswapError> {
+    admin.require_auth();
+    if *admin != get_admin(e) {
+        return Err(SoroswapError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Output of `amount` along `path` at the current spot price of each pair
+///
+/// Chains `router_quote` over the reserves, i.e. the output the swap would produce with no
+/// price impact and no pair fee.
+fn spot_amount_out(router: &SoroswapRouterClient, path: &SwapPath, amount: i128) -> i128 {
+    let factory = router.get_factory();
+    let path = path.as_vec();
+
+    let mut spot_amount = amount;
+    for i in 0..path.len() - 1 {
+        let (reserve_in, reserve_out) = router.get_reserves(&factory, &path.get_unchecked(i), &path.get_unchecked(i + 1));
+        spot_amount = router.router_quote(&spot_amount, &reserve_in, &reserve_out);
+    }
+    spot_amount
+}
+
+/// Executes a swap through the router on behalf of `caller`, records it in the stats and
+/// emits `SwapExecuted`
+///
+/// When the admin has set a maximum price impact, the realized output is compared to the
+/// spot-price output and the swap reverts with `ExcessiveSlippage` if it falls short by more
+/// than `max_impact` units of the configured denominator (the pair fee counts towards the
+/// impact). This protects callers even when `amount_out_min` is set loosely.
+fn execute_swap(
+    e: &Env,
+    router: &SoroswapRouterClient,
+    caller: &Address,
+    path: &SwapPath,
+    amount: i128,
+    amount_out_min: i128,
+    deadline: u64,
+) -> Result<SwapReceipt, SoroswapError> {
+    ensure_not_expired(e, deadline, SoroswapError::DeadlineExpired)?;
+
+    let max_impact = get_max_impact(e);
+    let spot_amount = if max_impact > 0 {
+        spot_amount_out(router, path, amount)
+    } else {
+        0
+    };
+
+    // The caller's signature authorizes the router to transfer tokens directly
+    // from their account - this contract never takes custody
+    let swap_result = router.swap_exact_tokens_for_tokens(
+        &amount,         // Exact amount to swap
+        &amount_out_min, // Minimum amount out
+        path.as_vec(),   // Swap route
+        caller,          // Recipient of output tokens (same as sender)
+        &deadline,       // Deadline
+    );
+    let amount_out = swap_result.last().unwrap();
+
+    if spot_amount > amount_out {
+        let impact = mul_div(e, spot_amount - amount_out, get_denominator(e), spot_amount, Rounding::Up)?;
+        if impact > max_impact as i128 {
+            return Err(SoroswapError::ExcessiveSlippage);
+        }
+    }
+
+    record_swap(e, &path.token_in(), amount);
+
+    let receipt = SwapReceipt::new(path.as_vec(), amount, amount_out);
+    SwapExecuted { receipt: receipt.clone() }.publish(e);
+
+    Ok(receipt)
+}
+
+/// Minimum output for a swap whose caller passed an expected output instead of a minimum
+///
+/// Applies the admin-set default slippage of the `(token_in, token_out)` pair to
+/// `expected_amount_out`, which the caller quoted off-chain before signing. An on-chain quote
+/// can't serve as the reference: it reads the same reserves the swap executes against, so a
+/// price moved by a front-runner moves the quote too. Without a default slippage the expected
+/// output itself is the minimum; without an expected output there is no minimum.
+fn min_from_expected_out(e: &Env, path: &SwapPath, expected_amount_out: Option<i128>) -> Result<i128, SoroswapError> {
+    let Some(expected_amount_out) = expected_amount_out else {
+        return Ok(0);
+    };
+    check_nonnegative_amount(expected_amount_out)?;
+
+    let slippage = get_pair_slippage(e, &path.token_in(), &path.token_out());
+    let denominator = get_denominator(e);
+    Ok(mul_div(e, expected_amount_out, denominator - slippage as i128, denominator, Rounding::Down)?)
+}
+
 #[contract]
 struct SoroswapSimple;
 
 #[contractimpl]
 impl SoroswapSimple {
-    /// Initialize the contract with the Soroswap Router address
+    /// Initialize the contract with the admin and the Soroswap Router address
+    ///
+    /// The router address is stored and used for all subsequent swap operations.
+    /// The admin manages the swap guards (e.g. the maximum price impact).
     ///
-    /// This address is stored and used for all subsequent swap operations
-    pub fn __constructor(e: Env, router_address: Address) {
+    /// `denominator` fixes the unit of the price impact cap and pair slippage defaults for the
+    /// contract's lifetime: `BPS_DENOMINATOR` (10_000, basis points) or `PPM_DENOMINATOR`
+    /// (1_000_000, parts per million). Ppm allows sub-basis-point caps at the cost of less
+    /// readable settings.
+    pub fn __constructor(e: Env, admin: Address, router_address: Address, denominator: u32) {
+        let denominator = denominator as i128;
+        if denominator != BPS_DENOMINATOR && denominator != PPM_DENOMINATOR {
+            panic!("denominator must be 10_000 (bps) or 1_000_000 (ppm)");
+        }
+        set_admin(&e, admin);
         set_soroswap_router_address(&e, router_address);
+        set_denominator(&e, denominator);
     }
 
     /// Execute a token swap via Soroswap Router as a direct proxy
     ///
     /// ## Authorization Flow:
-    /// 1. User signs the transaction (`caller.require_auth()`)
+    /// 1. User signs the transaction, scoped to
+    ///    `(token_in, token_out, amount, expected_amount_out, deadline)`
+    ///    (`caller.require_auth_for_args(...)`)
     /// 2. User's signature authorizes the Router to transfer tokens from their account
     /// 3. This contract acts as coordinator - tokens never pass through it
     ///
+    /// The signature covers every argument except `caller` itself, so whoever relays the
+    /// user's auth entry can't pick a different pair, amount, expected output or deadline.
+    ///
     /// ## Parameters:
     /// - `caller`: The user executing the swap (must sign the transaction)
     /// - `token_in`: Token being sold
     /// - `token_out`: Token being purchased
     /// - `amount`: Amount of `token_in` to swap
+    /// - `expected_amount_out`: Output quoted off-chain; the swap reverts if it returns less than
+    ///   this minus the pair's default slippage (`None` = no minimum)
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
     ///
     /// ## Returns:
-    /// Amount of `token_out` received from the swap
-    pub fn swap(e: Env, caller: Address, token_in: Address, token_out: Address, amount: i128) -> Result<i128, SoroswapError> {
-        // Verify the caller has signed this transaction
-        caller.require_auth();
+    /// `SwapReceipt` of the swap (`amount_out` is the amount of `token_out` received)
+    pub fn swap(e: Env, caller: Address, token_in: Address, token_out: Address, amount: i128, expected_amount_out: Option<i128>, deadline: Option<u64>) -> Result<SwapReceipt, SoroswapError> {
+        // Verify the caller has signed this transaction for exactly these swap parameters
+        caller.require_auth_for_args(vec![
+            &e,
+            token_in.into_val(&e),
+            token_out.into_val(&e),
+            amount.into_val(&e),
+            expected_amount_out.into_val(&e),
+            deadline.into_val(&e),
+        ]);
         check_nonnegative_amount(amount)?;
         extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
 
         // Get the stored Soroswap Router address and create client
         let soroswap_router_address = get_soroswap_router_address(&e);
         let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
 
         // Build the swap path (direct pair: token_in -> token_out)
-        let mut path: Vec<Address> = Vec::new(&e);
-        path.push_back(token_in.clone());
-        path.push_back(token_out.clone());
+        let path = SwapPath::direct(&e, &token_in, &token_out, SoroswapError::InvalidArgument)?;
+
+        // Snapshot this contract's balances to prove the swap leaves nothing behind
+        let this = e.current_contract_address();
+        let token_in_client = token::Client::new(&e, &token_in);
+        let token_out_client = token::Client::new(&e, &token_out);
+        let token_in_before = token_in_client.balance(&this);
+        let token_out_before = token_out_client.balance(&this);
 
         // Execute the swap through the router
-        // The caller's signature authorizes the router to transfer tokens directly
-        // from their account - this contract never takes custody
-        let swap_result = soroswap_router_client.swap_exact_tokens_for_tokens(
-            &amount,     // Exact amount to swap
-            &0,          // Minimum amount out (0 for simplicity; use slippage calculation in production)
-            &path,       // Swap route
-            &caller,     // Recipient of output tokens (same as sender in this case)
-            &u64::MAX,   // Deadline (max for simplicity; use actual timestamp in production)
-        );
-
-        // Return the amount of token_out received
-        let total_swapped_amount = swap_result.last().unwrap();
-
-        Ok(total_swapped_amount)
+        // The minimum is the caller's expected output less the pair's default slippage
+        let amount_out_min = min_from_expected_out(&e, &path, expected_amount_out)?;
+        let receipt = execute_swap(&e, &soroswap_router_client, &caller, &path, amount, amount_out_min, deadline)?;
+
+        // Invariant: the proxy never holds tokens (catches e.g. a misconfigured router)
+        if token_in_client.balance(&this) != token_in_before || token_out_client.balance(&this) != token_out_before {
+            return Err(SoroswapError::InvariantViolation);
+        }
+
+        Ok(receipt)
+    }
+
+    /// Execute a multi-hop token swap via Soroswap Router as a direct proxy
+    ///
+    /// Same authorization flow as `swap`, but the caller provides the full route
+    /// (e.g. `[EURC, XLM, USDC]`). The path is validated as a `SwapPath`.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user executing the swap (must sign the transaction)
+    /// - `path`: Swap route, starting with the token sold and ending with the token purchased
+    /// - `amount`: Amount of the first token in `path` to swap
+    /// - `expected_amount_out`: Output quoted off-chain; the swap reverts if it returns less than
+    ///   this minus the default slippage of the (first, last) token pair (`None` = no minimum)
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `SwapReceipt` of the swap (`amount_out` is the amount of the last token in `path` received)
+    pub fn swap_with_path(e: Env, caller: Address, path: Vec<Address>, amount: i128, expected_amount_out: Option<i128>, deadline: Option<u64>) -> Result<SwapReceipt, SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        let path = SwapPath::new(path, get_max_path_len(&e), SoroswapError::InvalidArgument)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        // Execute the swap through the router, hopping through every pair in `path`
+        // The minimum is the caller's expected output less the (first, last) pair's default slippage
+        let amount_out_min = min_from_expected_out(&e, &path, expected_amount_out)?;
+        execute_swap(&e, &soroswap_router_client, &caller, &path, amount, amount_out_min, deadline)
+    }
+
+    /// Execute a direct swap and also return its effective exchange rate
+    ///
+    /// Same flow as `swap`, for clients that log execution quality. The rate is
+    /// `amount_out * PRICE_SCALE / amount_in`, i.e. `token_out` received per unit of `token_in`
+    /// with 7 decimals (0 when `amount` is 0).
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user executing the swap (must sign the transaction)
+    /// - `token_in`: Token being sold
+    /// - `token_out`: Token being purchased
+    /// - `amount`: Amount of `token_in` to swap
+    /// - `amount_out_min`: Minimum amount of `token_out` to receive, otherwise the swap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `(SwapReceipt of the swap, effective rate scaled by PRICE_SCALE)`
+    pub fn swap_with_rate(e: Env, caller: Address, token_in: Address, token_out: Address, amount: i128, amount_out_min: i128, deadline: Option<u64>) -> Result<(SwapReceipt, i128), SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let path = SwapPath::direct(&e, &token_in, &token_out, SoroswapError::InvalidArgument)?;
+        let receipt = execute_swap(&e, &soroswap_router_client, &caller, &path, amount, amount_out_min, deadline)?;
+
+        let rate = if amount == 0 {
+            0
+        } else {
+            mul_div(&e, receipt.amount_out, PRICE_SCALE, amount, Rounding::Down)?
+        };
+
+        Ok((receipt, rate))
+    }
+
+    /// Swap the caller's entire balance of `token_in` into `token_out`
+    ///
+    /// Useful for "dust to one token" flows: the balance is read on-chain, so clients don't
+    /// need to query it first (and can't race against incoming transfers).
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user executing the swap (must sign the transaction)
+    /// - `token_in`: Token being sold (the full balance is swapped)
+    /// - `token_out`: Token being purchased
+    /// - `amount_out_min`: Minimum amount of `token_out` to receive, otherwise the swap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `SwapReceipt` of the swap (`amount_in` is the balance that was swapped)
+    pub fn swap_all(e: Env, caller: Address, token_in: Address, token_out: Address, amount_out_min: i128, deadline: Option<u64>) -> Result<SwapReceipt, SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        // Read the caller's full balance of token_in
+        let amount = token::Client::new(&e, &token_in).balance(&caller);
+        if amount == 0 {
+            return Err(SoroswapError::ZeroBalance);
+        }
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let path = SwapPath::direct(&e, &token_in, &token_out, SoroswapError::InvalidArgument)?;
+
+        execute_swap(&e, &soroswap_router_client, &caller, &path, amount, amount_out_min, deadline)
+    }
+
+    /// Returns the total number of swaps executed through this contract and the
+    /// cumulative amount of `token` sold in them
+    pub fn get_stats(e: Env, token: Address) -> (u64, i128) {
+        (get_swap_count(&e), get_swap_volume(&e, &token))
+    }
+
+    /// Returns whether `caller` holds enough `token_in` to swap `amount`
+    ///
+    /// Read-only pre-check for front-ends before asking for a signature. The router
+    /// pulls funds straight from `caller` under its auth, so no allowance is involved.
+    pub fn can_swap(e: Env, caller: Address, token_in: Address, amount: i128) -> bool {
+        if amount < 0 {
+            return false;
+        }
+        token::Client::new(&e, &token_in).balance(&caller) >= amount
+    }
+
+    /// Execute several swaps in one transaction, each paying out to its own recipient
+    ///
+    /// Useful for payout contracts: one signature from `caller` funds every job.
+    ///
+    /// ## Token Flow (per job):
+    /// ```
+    /// Caller (token_in) → Router → Pair → Caller (token_out) → job.to
+    /// ```
+    /// The Soroswap Router pulls `token_in` from its `to` argument, so each swap settles to the
+    /// caller first and the output is then forwarded to the job's recipient.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user funding every swap (must sign the transaction)
+    /// - `jobs`: The swaps to execute (see `SwapJob`)
+    /// - `deadline`: Unix timestamp after which the batch reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// Amount of `token_out` delivered for each job, in order
+    pub fn batch_swap(e: Env, caller: Address, jobs: Vec<SwapJob>, deadline: Option<u64>) -> Result<Vec<i128>, SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        if jobs.is_empty() {
+            return Err(SoroswapError::InvalidArgument);
+        }
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let mut amounts_out: Vec<i128> = Vec::new(&e);
+        for job in jobs.iter() {
+            check_nonnegative_amount(job.amount)?;
+            check_nonnegative_amount(job.amount_out_min)?;
+            let path = SwapPath::direct(&e, &job.token_in, &job.token_out, SoroswapError::InvalidArgument)?;
+
+            // The router settles to the caller...
+            let amount_out = execute_swap(&e, &soroswap_router_client, &caller, &path, job.amount, job.amount_out_min, deadline)?.amount_out;
+
+            // ...who forwards the output to the job's recipient
+            if job.to != caller {
+                safe_transfer(&e, &job.token_out, &caller, &job.to, amount_out, SoroswapError::TransferMismatch)?;
+            }
+
+            amounts_out.push_back(amount_out);
+        }
+
+        Ok(amounts_out)
+    }
+
+    /// Set the maximum realized price impact of a swap (admin only)
+    ///
+    /// Expressed in units of the denominator chosen at construction, relative to the spot-price
+    /// output (e.g. 1% is 100 in bps or 10_000 in ppm: swaps may return at most 1% less than
+    /// the spot price, fee included). Set to 0 to disable the check.
+    pub fn set_price_impact_cap(e: Env, admin: Address, max_impact: u32) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_price_impact_cap", vec![&e, max_impact.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        if max_impact as i128 > get_denominator(&e) {
+            return Err(SoroswapError::InvalidArgument);
+        }
+        set_max_impact(&e, max_impact);
+        Ok(())
+    }
+
+    /// Set the default slippage of a `(token_in, token_out)` pair (admin only)
+    ///
+    /// Applied by `swap` and `swap_with_path`, which take an expected output instead of a minimum:
+    /// the swap reverts if it returns less than the caller's `expected_amount_out` minus
+    /// `slippage`. Expressed in units of the denominator chosen at construction; 0 removes the
+    /// default (the expected output is then the minimum). Pairs are directional.
+    ///
+    /// Limited to those two entry points, and only when an expected output is passed: with
+    /// `None` there is no off-chain reference to apply it to, and `swap_with_rate`, `swap_all`,
+    /// `batch_swap` and `best_path_swap` take the caller's explicit `amount_out_min` as is.
+    pub fn set_pair_slippage(e: Env, admin: Address, token_in: Address, token_out: Address, slippage: u32) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_pair_slippage", vec![&e, token_in.into_val(&e), token_out.into_val(&e), slippage.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        if slippage as i128 > get_denominator(&e) {
+            return Err(SoroswapError::InvalidArgument);
+        }
+        set_pair_slippage(&e, &token_in, &token_out, slippage);
+        Ok(())
+    }
+
+    /// Set the maximum number of tokens in a multi-hop swap path (admin only)
+    ///
+    /// Every hop is an extra pair call, so the cap bounds the budget a single swap can burn.
+    /// Defaults to `DEFAULT_MAX_PATH_LEN`; must allow at least one hop (2 tokens).
+    pub fn set_max_path_len(e: Env, admin: Address, max_path_len: u32) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        check_timelock(&e, "set_max_path_len", vec![&e, max_path_len.into_val(&e)])?;
+        extend_instance_ttl(&e);
+
+        if max_path_len < 2 {
+            return Err(SoroswapError::InvalidArgument);
+        }
+        set_max_path_len(&e, max_path_len);
+        Ok(())
+    }
+
+    /// Returns the maximum number of tokens in a multi-hop swap path
+    pub fn get_max_path_len(e: Env) -> u32 {
+        get_max_path_len(&e)
+    }
+
+    /// Returns the denominator of the price impact cap and pair slippage defaults
+    ///
+    /// 10_000 for basis points, 1_000_000 for parts per million.
+    pub fn get_denominator(e: Env) -> i128 {
+        get_denominator(&e)
+    }
+
+    /// Returns the swap fee of the `token_in`/`token_out` pair, in basis points
+    ///
+    /// Lets clients compute the true cost of a swap. Soroswap pairs don't expose their fee,
+    /// and every pair charges `PAIR_FEE_BPS`, so this only checks that the pair exists.
+    pub fn get_pair_fee(e: Env, token_in: Address, token_out: Address) -> u32 {
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        // Reverts if the pair doesn't exist
+        soroswap_router_client.get_reserves(&soroswap_router_client.get_factory(), &token_in, &token_out);
+        PAIR_FEE_BPS
+    }
+
+    /// Returns the Soroswap pair address for `token_in`/`token_out`
+    ///
+    /// Read-only helper for clients that need to approve or inspect the pool directly.
+    pub fn get_pair(e: Env, token_in: Address, token_out: Address) -> Address {
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        soroswap_router_client.router_pair_for(&token_in, &token_out)
+    }
+
+    /// Schedule a timelocked admin operation (admin only)
+    ///
+    /// While the timelock delay is non-zero, every admin setter only runs once its
+    /// `op_hash` (see `get_op_hash`) has been scheduled and `execute_after` has passed.
+    /// `execute_after` must be at least `now + timelock delay`.
+    pub fn schedule(e: Env, admin: Address, op_hash: BytesN<32>, execute_after: u64) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::schedule(&e, &op_hash, execute_after)?;
+        Ok(())
+    }
+
+    /// Cancel a scheduled admin operation (admin only)
+    pub fn cancel(e: Env, admin: Address, op_hash: BytesN<32>) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::cancel(&e, &op_hash);
+        Ok(())
+    }
+
+    /// Set the timelock delay in seconds (admin only, 0 = disabled)
+    ///
+    /// Changing the delay is itself timelocked once a delay is in place, so a compromised
+    /// admin can't disable it instantly. At most `MAX_TIMELOCK_DELAY` (30 days).
+    pub fn set_timelock_delay(e: Env, admin: Address, delay: u64) -> Result<(), SoroswapError> {
+        check_admin(&e, &admin)?;
+        extend_instance_ttl(&e);
+
+        timelock::set_delay(&e, delay)?;
+        Ok(())
+    }
+
+    /// Returns the current timelock delay in seconds (0 = disabled)
+    pub fn get_timelock_delay(e: Env) -> u64 {
+        timelock::get_delay(&e)
+    }
+
+    /// Returns the hash to `schedule` for calling `fn_name` with `args` (without `admin`)
+    pub fn get_op_hash(e: Env, fn_name: Symbol, args: Vec<Val>) -> BytesN<32> {
+        op_hash(&e, fn_name, args)
+    }
+
+    /// Quote several candidate routes and swap through the one with the highest output
+    ///
+    /// A minimal router-aggregation helper: each candidate is quoted with
+    /// `router_get_amounts_out` and the best one is executed like `swap_with_path`.
+    ///
+    /// ## Parameters:
+    /// - `caller`: The user executing the swap (must sign the transaction)
+    /// - `token_in`: Token being sold (first token of every candidate)
+    /// - `token_out`: Token being purchased (last token of every candidate)
+    /// - `amount`: Amount of `token_in` to swap
+    /// - `candidate_paths`: Up to `MAX_CANDIDATE_PATHS` routes to compare
+    /// - `amount_out_min`: Minimum amount of `token_out` to receive, otherwise the swap reverts
+    /// - `deadline`: Unix timestamp after which the swap reverts (`None` = now + `DEFAULT_DEADLINE_WINDOW`)
+    ///
+    /// ## Returns:
+    /// `(index of the chosen candidate, amount of token_out received)`
+    #[allow(clippy::too_many_arguments)]
+    pub fn best_path_swap(
+        e: Env,
+        caller: Address,
+        token_in: Address,
+        token_out: Address,
+        amount: i128,
+        candidate_paths: Vec<Vec<Address>>,
+        amount_out_min: i128,
+        deadline: Option<u64>,
+    ) -> Result<(u32, i128), SoroswapError> {
+        // Verify the caller has signed this transaction
+        caller.require_auth();
+        check_nonnegative_amount(amount)?;
+        check_nonnegative_amount(amount_out_min)?;
+        extend_instance_ttl(&e);
+        let deadline = resolve_deadline(&e, deadline);
+
+        let soroswap_router_address = get_soroswap_router_address(&e);
+        let soroswap_router_client = SoroswapRouterClient::new(&e, &soroswap_router_address);
+
+        let quote = |path: &SwapPath| match soroswap_router_client.try_router_get_amounts_out(&amount, path.as_vec()) {
+            Ok(Ok(amounts)) => Some(amounts.last_unchecked()),
+            _ => None,
+        };
+        let (index, path) = select_best(candidate_paths, &token_in, &token_out, get_max_path_len(&e), quote, SoroswapError::InvalidArgument, SoroswapError::SwapFailed)?;
+        let receipt = execute_swap(&e, &soroswap_router_client, &caller, &path, amount, amount_out_min, deadline)?;
+
+        Ok((index, receipt.amount_out))
     }
 }
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address};
+
+/// A single swap of a `batch_swap` call
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SwapJob {
+    /// Token being sold
+    pub token_in: Address,
+    /// Token being purchased
+    pub token_out: Address,
+    /// Amount of `token_in` to swap
+    pub amount: i128,
+    /// Recipient of the `token_out` received
+    pub to: Address,
+    /// Minimum amount of `token_out` to receive, otherwise the whole batch reverts
+    pub amount_out_min: i128,
+}
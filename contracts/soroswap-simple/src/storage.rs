@@ -4,7 +4,14 @@ use soroban_sdk::{contracttype, Address, Env};
 #[contracttype]
 
 enum DataKey {
+    Admin,
     SoroswapRouterAddress,
+    MaxImpact,
+    Denominator,
+    SwapCount,
+    SwapVolume(Address),
+    PairSlippage(Address, Address),
+    MaxPathLen,
 }
 
 #[allow(dead_code)]
@@ -13,6 +20,10 @@ const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 #[allow(dead_code)]
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+#[allow(dead_code)]
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 pub fn extend_instance_ttl(e: &Env) {
     e.storage()
@@ -20,6 +31,20 @@ pub fn extend_instance_ttl(e: &Env) {
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
+// Admin
+pub fn set_admin(e: &Env, address: Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Admin, &address);
+}
+
+pub fn get_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap()
+}
+
 // Soroswap Router Address
 pub fn set_soroswap_router_address(e: &Env, address: Address) {
     e.storage()
@@ -33,3 +58,99 @@ pub fn get_soroswap_router_address(e: &Env) -> Address {
         .get(&DataKey::SoroswapRouterAddress)
         .unwrap()
 }
+
+// Maximum realized price impact of a swap (in units of the denominator, 0 = disabled)
+pub fn set_max_impact(e: &Env, max_impact: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::MaxImpact, &max_impact);
+}
+
+pub fn get_max_impact(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MaxImpact)
+        .unwrap_or(0)
+}
+
+// Denominator of the price impact cap (`BPS_DENOMINATOR` or `PPM_DENOMINATOR`)
+pub fn set_denominator(e: &Env, denominator: i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Denominator, &denominator);
+}
+
+pub fn get_denominator(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::Denominator)
+        .unwrap_or(crate::BPS_DENOMINATOR)
+}
+
+// Swap statistics
+pub fn get_swap_count(e: &Env) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::SwapCount)
+        .unwrap_or(0)
+}
+
+pub fn get_swap_volume(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::SwapVolume(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Increments the swap count and adds `amount_in` to the cumulative volume of `token_in`
+pub fn record_swap(e: &Env, token_in: &Address, amount_in: i128) {
+    let count_key = DataKey::SwapCount;
+    e.storage()
+        .persistent()
+        .set(&count_key, &(get_swap_count(e) + 1));
+    e.storage()
+        .persistent()
+        .extend_ttl(&count_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+    let volume_key = DataKey::SwapVolume(token_in.clone());
+    e.storage()
+        .persistent()
+        .set(&volume_key, &(get_swap_volume(e, token_in) + amount_in));
+    e.storage()
+        .persistent()
+        .extend_ttl(&volume_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Default slippage of a (token_in, token_out) pair, in units of the denominator (0 = none)
+pub fn set_pair_slippage(e: &Env, token_in: &Address, token_out: &Address, slippage: u32) {
+    let key = DataKey::PairSlippage(token_in.clone(), token_out.clone());
+    if slippage > 0 {
+        e.storage().persistent().set(&key, &slippage);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_pair_slippage(e: &Env, token_in: &Address, token_out: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::PairSlippage(token_in.clone(), token_out.clone()))
+        .unwrap_or(0)
+}
+
+// Maximum number of tokens in a multi-hop swap path
+pub fn set_max_path_len(e: &Env, max_path_len: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::MaxPathLen, &max_path_len);
+}
+
+pub fn get_max_path_len(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::MaxPathLen)
+        .unwrap_or(workshop_common::path::DEFAULT_MAX_PATH_LEN)
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    vec, IntoVal, Val, Vec,
+};
+use workshop_common::time::DEFAULT_DEADLINE_WINDOW;
+
+use super::Setup;
+
+/// Authorizes the user's signature over a `swap` of `signed_amount` token_a, and the router
+/// pull it covers
+fn sign_swap(setup: &Setup, signed_amount: i128) {
+    let path = vec![&setup.e, setup.token_a.clone(), setup.token_b.clone()];
+    let swap_args: Vec<Val> = (setup.token_a.clone(), setup.token_b.clone(), signed_amount, None::<i128>, None::<u64>).into_val(&setup.e);
+    let router_args: Vec<Val> = (signed_amount, 0i128, path, setup.user.clone(), DEFAULT_DEADLINE_WINDOW).into_val(&setup.e);
+    let pull_args: Vec<Val> = (setup.user.clone(), setup.router.address.clone(), signed_amount).into_val(&setup.e);
+    setup.e.mock_auths(&[MockAuth {
+        address: &setup.user,
+        invoke: &MockAuthInvoke {
+            contract: &setup.contract.address,
+            fn_name: "swap",
+            args: swap_args,
+            sub_invokes: &[MockAuthInvoke {
+                contract: &setup.router.address,
+                fn_name: "swap_exact_tokens_for_tokens",
+                args: router_args,
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &setup.token_a,
+                    fn_name: "transfer",
+                    args: pull_args,
+                    sub_invokes: &[],
+                }],
+            }],
+        },
+    }]);
+}
+
+#[test]
+fn signature_authorizes_the_signed_amount() {
+    let setup = Setup::new();
+
+    sign_swap(&setup, 100);
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    assert_eq!(receipt.amount_out, 200);
+}
+
+#[test]
+fn signature_scoped_to_one_amount_cannot_swap_another() {
+    let setup = Setup::new();
+
+    sign_swap(&setup, 100);
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &200, &None, &None);
+
+    assert!(result.is_err());
+    assert_eq!(setup.router.swap_calls(), 0);
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use super::{Setup, FUNDS};
+use crate::{error::SoroswapError, SwapJob};
+
+fn job(setup: &Setup, amount: i128, to: &Address, amount_out_min: i128) -> SwapJob {
+    SwapJob {
+        token_in: setup.token_a.clone(),
+        token_out: setup.token_b.clone(),
+        amount,
+        to: to.clone(),
+        amount_out_min,
+    }
+}
+
+#[test]
+fn batch_swap_pays_each_recipient() {
+    let setup = Setup::new();
+    let alice = Address::generate(&setup.e);
+    let bob = Address::generate(&setup.e);
+    let jobs = vec![&setup.e, job(&setup, 100, &alice, 200), job(&setup, 30, &bob, 60)];
+
+    let amounts_out = setup.contract.batch_swap(&setup.user, &jobs, &None);
+
+    assert_eq!(amounts_out, vec![&setup.e, 200, 60]);
+    assert_eq!(setup.balance(&setup.token_b, &alice), 200);
+    assert_eq!(setup.balance(&setup.token_b, &bob), 60);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 0);
+    assert_eq!(setup.balance(&setup.token_a, &setup.user), FUNDS - 130);
+}
+
+#[test]
+fn batch_swap_rejects_an_empty_batch() {
+    let setup = Setup::new();
+
+    let result = setup.contract.try_batch_swap(&setup.user, &vec![&setup.e], &None);
+    assert_eq!(result, Err(Ok(SoroswapError::InvalidArgument)));
+}
+
+#[test]
+fn one_short_job_reverts_the_whole_batch() {
+    let setup = Setup::new();
+    let alice = Address::generate(&setup.e);
+    let bob = Address::generate(&setup.e);
+    let jobs = vec![&setup.e, job(&setup, 100, &alice, 200), job(&setup, 30, &bob, 61)];
+
+    assert!(setup.contract.try_batch_swap(&setup.user, &jobs, &None).is_err());
+    assert_eq!(setup.balance(&setup.token_b, &alice), 0);
+    assert_eq!(setup.balance(&setup.token_a, &setup.user), FUNDS);
+}
@@ -0,0 +1,10 @@
+use super::{Setup, FUNDS};
+
+#[test]
+fn can_swap_checks_the_balance() {
+    let setup = Setup::new();
+
+    assert!(setup.contract.can_swap(&setup.user, &setup.token_a, &FUNDS));
+    assert!(!setup.contract.can_swap(&setup.user, &setup.token_a, &(FUNDS + 1)));
+    assert!(!setup.contract.can_swap(&setup.user, &setup.token_b, &1));
+}
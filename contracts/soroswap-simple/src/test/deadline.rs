@@ -0,0 +1,42 @@
+use soroban_sdk::{testutils::Ledger, IntoVal};
+use workshop_common::time::DEFAULT_DEADLINE_WINDOW;
+
+use super::Setup;
+use crate::error::SoroswapError;
+
+/// Deadline the router was called with in the last swap
+fn router_deadline(setup: &Setup) -> u64 {
+    setup.router_swap_args().get_unchecked(4).into_val(&setup.e)
+}
+
+#[test]
+fn missing_deadline_defaults_to_now_plus_window() {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    assert_eq!(router_deadline(&setup), 1_000 + DEFAULT_DEADLINE_WINDOW);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+}
+
+#[test]
+fn explicit_deadline_is_passed_to_the_router() {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &Some(5_000));
+
+    assert_eq!(router_deadline(&setup), 5_000);
+}
+
+#[test]
+fn expired_deadline_reverts() {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &Some(999));
+
+    assert_eq!(result, Err(Ok(SoroswapError::DeadlineExpired)));
+    assert_eq!(setup.router.swap_calls(), 0);
+}
@@ -0,0 +1,54 @@
+use super::Setup;
+use crate::{error::SoroswapError, SoroswapSimple, BPS_DENOMINATOR, PPM_DENOMINATOR};
+
+#[test]
+fn one_percent_slippage_under_both_denominators() {
+    for (denominator, one_percent) in [(BPS_DENOMINATOR, 100), (PPM_DENOMINATOR, 10_000)] {
+        let setup = Setup::with_denominator(denominator as u32);
+        assert_eq!(setup.contract.get_denominator(), denominator);
+        setup.contract.set_pair_slippage(&setup.admin, &setup.token_a, &setup.token_b, &one_percent);
+
+        setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &Some(200), &None);
+
+        assert_eq!(setup.router_amount_out_min(), 198);
+    }
+}
+
+#[test]
+fn ppm_allows_sub_basis_point_slippage() {
+    let setup = Setup::with_denominator(PPM_DENOMINATOR as u32);
+    // 50 ppm = 0.5 bps
+    setup.contract.set_pair_slippage(&setup.admin, &setup.token_a, &setup.token_b, &50);
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &500_000, &Some(1_000_000), &None);
+
+    assert_eq!(setup.router_amount_out_min(), 999_950);
+}
+
+#[test]
+fn settings_are_bounded_by_the_denominator() {
+    let bps = Setup::new();
+    let result = bps.contract.try_set_pair_slippage(&bps.admin, &bps.token_a, &bps.token_b, &10_001);
+    assert_eq!(result, Err(Ok(SoroswapError::InvalidArgument)));
+
+    let ppm = Setup::with_denominator(PPM_DENOMINATOR as u32);
+    ppm.contract.set_pair_slippage(&ppm.admin, &ppm.token_a, &ppm.token_b, &10_001);
+}
+
+#[test]
+fn price_impact_cap_under_ppm() {
+    let setup = Setup::with_denominator(PPM_DENOMINATOR as u32);
+    // 10% cap, against a 50% impact
+    setup.contract.set_price_impact_cap(&setup.admin, &100_000);
+    setup.router.set_reserves(&setup.token_a, &setup.token_b, &1_000, &4_000);
+
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+    assert_eq!(result, Err(Ok(SoroswapError::ExcessiveSlippage)));
+}
+
+#[test]
+#[should_panic(expected = "denominator must be 10_000 (bps) or 1_000_000 (ppm)")]
+fn other_denominators_are_rejected() {
+    let setup = Setup::new();
+    setup.e.register(SoroswapSimple, (setup.admin.clone(), setup.router.address.clone(), 1_000u32));
+}
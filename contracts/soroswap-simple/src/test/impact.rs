@@ -0,0 +1,42 @@
+use super::Setup;
+use crate::error::SoroswapError;
+
+/// 10% price impact cap
+fn capped() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.contract.set_price_impact_cap(&setup.admin, &1_000);
+    setup
+}
+
+#[test]
+fn high_impact_swap_reverts() {
+    let setup = capped();
+    // Spot price of 4 token_b per token_a, while the router only pays 2: 50% impact
+    setup.router.set_reserves(&setup.token_a, &setup.token_b, &1_000, &4_000);
+
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+    assert_eq!(result, Err(Ok(SoroswapError::ExcessiveSlippage)));
+}
+
+#[test]
+fn impact_within_the_cap_passes() {
+    let setup = capped();
+    // Spot output of 220 for a realized 200: about 9.1% impact
+    setup.router.set_reserves(&setup.token_a, &setup.token_b, &1_000, &2_200);
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+    assert_eq!(receipt.amount_out, 200);
+}
+
+#[test]
+fn impact_cap_applies_even_with_a_loose_minimum() {
+    let setup = capped();
+    setup.router.set_reserves(&setup.token_a, &setup.token_b, &1_000, &4_000);
+
+    let result = setup.contract.try_swap_with_rate(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &None);
+    assert_eq!(result, Err(Ok(SoroswapError::ExcessiveSlippage)));
+
+    setup.contract.set_price_impact_cap(&setup.admin, &0);
+    let (receipt, _) = setup.contract.swap_with_rate(&setup.user, &setup.token_a, &setup.token_b, &100, &0, &None);
+    assert_eq!(receipt.amount_out, 200);
+}
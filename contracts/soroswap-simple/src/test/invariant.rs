@@ -0,0 +1,25 @@
+use super::{Setup, FUNDS};
+use crate::error::SoroswapError;
+
+#[test]
+fn proxy_holds_no_tokens_after_a_swap() {
+    let setup = Setup::new();
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    assert_eq!(setup.balance(&setup.token_a, &setup.contract.address), 0);
+    assert_eq!(setup.balance(&setup.token_b, &setup.contract.address), 0);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), 200);
+}
+
+#[test]
+fn router_leaving_tokens_behind_violates_the_invariant() {
+    let setup = Setup::new();
+    setup.router.set_leak(&setup.contract.address, &1);
+
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    assert_eq!(result, Err(Ok(SoroswapError::InvariantViolation)));
+    assert_eq!(setup.balance(&setup.token_b, &setup.contract.address), 0);
+    assert_eq!(setup.balance(&setup.token_a, &setup.user), FUNDS);
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::Env;
+use workshop_common::math::{mul_div, Rounding};
+
+use crate::error::SoroswapError;
+
+fn checked_mul_div(e: &Env, a: i128, b: i128, denominator: i128) -> Result<i128, SoroswapError> {
+    Ok(mul_div(e, a, b, denominator, Rounding::Up)?)
+}
+
+#[test]
+fn math_errors_map_to_contract_errors() {
+    let e = Env::default();
+
+    assert_eq!(checked_mul_div(&e, 10, 3, 4), Ok(8));
+    assert_eq!(checked_mul_div(&e, i128::MAX, 2, 1), Err(SoroswapError::ArithmeticError));
+    assert_eq!(checked_mul_div(&e, 1, 1, 0), Err(SoroswapError::InvalidArgument));
+}
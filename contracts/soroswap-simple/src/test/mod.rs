@@ -0,0 +1,86 @@
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction},
+    token, Address, Env, IntoVal, Symbol, Val, Vec,
+};
+use workshop_mocks::{MockRouter, MockRouterClient};
+
+use crate::{SoroswapSimple, SoroswapSimpleClient, BPS_DENOMINATOR};
+
+mod auth;
+mod batch;
+mod can_swap;
+mod deadline;
+mod denominator;
+mod impact;
+mod invariant;
+mod math;
+mod pair;
+mod rate;
+mod receipt;
+mod slippage;
+mod stats;
+mod swap_all;
+mod timelock;
+
+/// Starting balance of `user` in `token_a` and of the router in `token_b`
+pub const FUNDS: i128 = 1_000_000_000;
+
+/// A `SoroswapSimple` in front of a mock router swapping `token_a -> token_b` at 1:2
+pub struct Setup<'a> {
+    pub e: Env,
+    pub admin: Address,
+    pub user: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    pub router: MockRouterClient<'a>,
+    pub contract: SoroswapSimpleClient<'a>,
+}
+
+impl Setup<'_> {
+    pub fn new() -> Self {
+        Self::with_denominator(BPS_DENOMINATOR as u32)
+    }
+
+    /// Same setup, with the contract constructed for `denominator` (bps or ppm)
+    pub fn with_denominator(denominator: u32) -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let admin = Address::generate(&e);
+        let user = Address::generate(&e);
+        let token_a = e.register_stellar_asset_contract_v2(admin.clone()).address();
+        let token_b = e.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let router = MockRouterClient::new(&e, &e.register(MockRouter, ()));
+        router.set_rate(&token_a, &token_b, &2, &1);
+        token::StellarAssetClient::new(&e, &token_a).mint(&user, &FUNDS);
+        token::StellarAssetClient::new(&e, &token_b).mint(&router.address, &FUNDS);
+
+        let contract_id = e.register(SoroswapSimple, (admin.clone(), router.address.clone(), denominator));
+        let contract = SoroswapSimpleClient::new(&e, &contract_id);
+
+        Setup { e, admin, user, token_a, token_b, router, contract }
+    }
+
+    pub fn balance(&self, token: &Address, id: &Address) -> i128 {
+        token::Client::new(&self.e, token).balance(id)
+    }
+
+    /// Arguments of the router swap made by the last call, read from the user's recorded auths
+    pub fn router_swap_args(&self) -> Vec<Val> {
+        let (_, root) = self.e.auths().into_iter().find(|(address, _)| *address == self.user).unwrap();
+        let router_call = root.sub_invocations.first().unwrap();
+        let AuthorizedFunction::Contract((contract, fn_name, args)) = &router_call.function else {
+            panic!("expected a contract call");
+        };
+        assert_eq!(*contract, self.router.address);
+        assert_eq!(*fn_name, Symbol::new(&self.e, "swap_exact_tokens_for_tokens"));
+        args.clone()
+    }
+
+    /// Minimum output the router was called with in the last swap
+    pub fn router_amount_out_min(&self) -> i128 {
+        self.router_swap_args().get_unchecked(1).into_val(&self.e)
+    }
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{testutils::Address as _, Address};
+
+use super::Setup;
+use crate::PAIR_FEE_BPS;
+
+#[test]
+fn get_pair_matches_the_router() {
+    let setup = Setup::new();
+    let pair = Address::generate(&setup.e);
+    setup.router.set_pair(&setup.token_a, &setup.token_b, &pair);
+
+    assert_eq!(setup.contract.get_pair(&setup.token_a, &setup.token_b), pair);
+    assert_eq!(setup.contract.get_pair(&setup.token_b, &setup.token_a), setup.router.router_pair_for(&setup.token_b, &setup.token_a));
+}
+
+#[test]
+fn get_pair_fee_returns_the_soroswap_fee_for_existing_pairs() {
+    let setup = Setup::new();
+
+    assert_eq!(setup.contract.get_pair_fee(&setup.token_a, &setup.token_b), PAIR_FEE_BPS);
+    assert_eq!(setup.contract.get_pair_fee(&setup.token_b, &setup.token_a), PAIR_FEE_BPS);
+
+    // No reserves: the pair doesn't exist
+    let unknown = Address::generate(&setup.e);
+    assert!(setup.contract.try_get_pair_fee(&setup.token_a, &unknown).is_err());
+}
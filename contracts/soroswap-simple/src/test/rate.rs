@@ -0,0 +1,24 @@
+use super::Setup;
+use crate::PRICE_SCALE;
+
+#[test]
+fn swap_with_rate_returns_the_effective_rate() {
+    let setup = Setup::new();
+
+    let (receipt, rate) = setup.contract.swap_with_rate(&setup.user, &setup.token_a, &setup.token_b, &100, &200, &None);
+
+    assert_eq!(receipt.amount_out, 200);
+    assert_eq!(rate, 2 * PRICE_SCALE);
+}
+
+#[test]
+fn effective_rate_rounds_down() {
+    let setup = Setup::new();
+    setup.router.set_rate(&setup.token_a, &setup.token_b, &2, &3);
+
+    let (receipt, rate) = setup.contract.swap_with_rate(&setup.user, &setup.token_a, &setup.token_b, &300, &0, &None);
+
+    // 200 / 300 with 7 decimals
+    assert_eq!(receipt.amount_out, 200);
+    assert_eq!(rate, 6_666_666);
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{testutils::{Address as _, Events}, vec, Address, Map, Symbol, TryFromVal};
+
+use super::Setup;
+use crate::SwapReceipt;
+
+/// Receipt of the last `SwapExecuted` event published by the contract
+pub fn swap_executed_receipt(setup: &Setup) -> SwapReceipt {
+    let (_, _, data) = setup
+        .e
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            let name = topics.first().and_then(|topic| Symbol::try_from_val(&setup.e, &topic).ok());
+            *contract == setup.contract.address && name == Some(Symbol::new(&setup.e, "swap_executed"))
+        })
+        .last()
+        .expect("no SwapExecuted event");
+    let fields = Map::<Symbol, SwapReceipt>::try_from_val(&setup.e, &data).unwrap();
+    fields.get(Symbol::new(&setup.e, "receipt")).unwrap()
+}
+
+#[test]
+fn direct_swap_receipt_fields() {
+    let setup = Setup::new();
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    let expected = SwapReceipt {
+        token_in: setup.token_a.clone(),
+        token_out: setup.token_b.clone(),
+        amount_in: 100,
+        amount_out: 200,
+        path_len: 2,
+    };
+    assert_eq!(receipt, expected);
+    assert_eq!(swap_executed_receipt(&setup), expected);
+    assert_eq!(setup.balance(&setup.token_b, &setup.user), receipt.amount_out);
+}
+
+#[test]
+fn multi_hop_receipt_fields() {
+    let setup = Setup::new();
+    let token_c = setup.e.register_stellar_asset_contract_v2(Address::generate(&setup.e)).address();
+    // a -> b at 1:2, then b -> c at 3:1
+    setup.router.set_rate(&setup.token_b, &token_c, &1, &3);
+    soroban_sdk::token::StellarAssetClient::new(&setup.e, &token_c).mint(&setup.router.address, &1_000);
+
+    let path = vec![&setup.e, setup.token_a.clone(), setup.token_b.clone(), token_c.clone()];
+    let receipt = setup.contract.swap_with_path(&setup.user, &path, &300, &None, &None);
+
+    let expected = SwapReceipt {
+        token_in: setup.token_a.clone(),
+        token_out: token_c.clone(),
+        amount_in: 300,
+        amount_out: 200,
+        path_len: 3,
+    };
+    assert_eq!(receipt, expected);
+    assert_eq!(swap_executed_receipt(&setup), expected);
+}
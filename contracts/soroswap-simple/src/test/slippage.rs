@@ -0,0 +1,57 @@
+use soroban_sdk::vec;
+
+use super::Setup;
+
+/// 1% default slippage on `token_a -> token_b`
+fn with_default_slippage() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.contract.set_pair_slippage(&setup.admin, &setup.token_a, &setup.token_b, &100);
+    setup
+}
+
+#[test]
+fn stored_default_applies_to_the_expected_output() {
+    let setup = with_default_slippage();
+
+    let receipt = setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &Some(200), &None);
+
+    // 200 expected less 1%
+    assert_eq!(setup.router_amount_out_min(), 198);
+    assert_eq!(receipt.amount_out, 200);
+}
+
+#[test]
+fn stored_default_applies_to_swap_with_path() {
+    let setup = with_default_slippage();
+    let path = vec![&setup.e, setup.token_a.clone(), setup.token_b.clone()];
+
+    setup.contract.swap_with_path(&setup.user, &path, &100, &Some(200), &None);
+
+    assert_eq!(setup.router_amount_out_min(), 198);
+}
+
+#[test]
+fn swap_reverts_below_the_expected_output_less_the_default() {
+    let setup = with_default_slippage();
+
+    // 203 expected less 1% is 200.97, rounded down to 200: still met
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &Some(203), &None);
+    assert_eq!(setup.router_amount_out_min(), 200);
+
+    // 204 expected less 1% is 201.96, rounded down to 201: the router returns 200
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &Some(204), &None);
+    assert!(result.is_err());
+}
+
+#[test]
+fn pairs_are_directional_and_none_means_no_minimum() {
+    let setup = with_default_slippage();
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+    assert_eq!(setup.router_amount_out_min(), 0);
+
+    // No default on token_b -> token_a: the expected output itself is the minimum
+    setup.router.set_rate(&setup.token_b, &setup.token_a, &1, &2);
+    setup.contract.swap(&setup.user, &setup.token_b, &setup.token_a, &100, &Some(50), &None);
+    assert_eq!(setup.router_amount_out_min(), 50);
+}
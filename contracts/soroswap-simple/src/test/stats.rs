@@ -0,0 +1,27 @@
+use super::Setup;
+
+#[test]
+fn stats_count_swaps_and_volume_per_token_in() {
+    let setup = Setup::new();
+    setup.router.set_rate(&setup.token_b, &setup.token_a, &1, &2);
+    assert_eq!(setup.contract.get_stats(&setup.token_a), (0, 0));
+
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &50, &None, &None);
+    setup.contract.swap(&setup.user, &setup.token_b, &setup.token_a, &40, &None, &None);
+
+    assert_eq!(setup.contract.get_stats(&setup.token_a), (3, 150));
+    assert_eq!(setup.contract.get_stats(&setup.token_b), (3, 40));
+}
+
+#[test]
+fn failed_swaps_are_not_counted() {
+    let setup = Setup::new();
+    setup.contract.swap(&setup.user, &setup.token_a, &setup.token_b, &100, &None, &None);
+
+    // 300 expected: the router's 200 falls short and the swap reverts
+    let result = setup.contract.try_swap(&setup.user, &setup.token_a, &setup.token_b, &100, &Some(300), &None);
+    assert!(result.is_err());
+
+    assert_eq!(setup.contract.get_stats(&setup.token_a), (1, 100));
+}
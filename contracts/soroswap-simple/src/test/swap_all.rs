@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::Address as _, token, Address};
+
+use super::Setup;
+use crate::error::SoroswapError;
+
+#[test]
+fn swap_all_consumes_the_full_balance() {
+    let setup = Setup::new();
+    let holder = Address::generate(&setup.e);
+    token::StellarAssetClient::new(&setup.e, &setup.token_a).mint(&holder, &300);
+
+    let receipt = setup.contract.swap_all(&holder, &setup.token_a, &setup.token_b, &600, &None);
+
+    assert_eq!(receipt.amount_in, 300);
+    assert_eq!(receipt.amount_out, 600);
+    assert_eq!(setup.balance(&setup.token_a, &holder), 0);
+    assert_eq!(setup.balance(&setup.token_b, &holder), 600);
+}
+
+#[test]
+fn swap_all_reverts_on_a_zero_balance() {
+    let setup = Setup::new();
+    let empty = Address::generate(&setup.e);
+
+    let result = setup.contract.try_swap_all(&empty, &setup.token_a, &setup.token_b, &0, &None);
+    assert_eq!(result, Err(Ok(SoroswapError::ZeroBalance)));
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{testutils::Ledger, vec, BytesN, IntoVal, Symbol};
+use workshop_common::timelock::MAX_TIMELOCK_DELAY;
+
+use super::Setup;
+use crate::error::SoroswapError;
+
+const DELAY: u64 = 3_600;
+
+/// A contract with a one hour timelock, at timestamp 1_000
+fn timelocked() -> Setup<'static> {
+    let setup = Setup::new();
+    setup.e.ledger().set_timestamp(1_000);
+    setup.contract.set_timelock_delay(&setup.admin, &DELAY);
+    setup
+}
+
+fn cap_op_hash(setup: &Setup, max_impact: u32) -> BytesN<32> {
+    setup.contract.get_op_hash(&Symbol::new(&setup.e, "set_price_impact_cap"), &vec![&setup.e, max_impact.into_val(&setup.e)])
+}
+
+#[test]
+fn unscheduled_operation_is_rejected() {
+    let setup = timelocked();
+
+    let result = setup.contract.try_set_price_impact_cap(&setup.admin, &100);
+    assert_eq!(result, Err(Ok(SoroswapError::OperationNotScheduled)));
+}
+
+#[test]
+fn premature_execution_is_rejected() {
+    let setup = timelocked();
+    setup.contract.schedule(&setup.admin, &cap_op_hash(&setup, 100), &(1_000 + DELAY));
+
+    setup.e.ledger().set_timestamp(1_000 + DELAY - 1);
+    let result = setup.contract.try_set_price_impact_cap(&setup.admin, &100);
+    assert_eq!(result, Err(Ok(SoroswapError::TimelockNotExpired)));
+}
+
+#[test]
+fn timely_execution_runs_once() {
+    let setup = timelocked();
+    setup.contract.schedule(&setup.admin, &cap_op_hash(&setup, 100), &(1_000 + DELAY));
+
+    setup.e.ledger().set_timestamp(1_000 + DELAY);
+    setup.contract.set_price_impact_cap(&setup.admin, &100);
+
+    // The schedule is consumed, and covered only the scheduled arguments
+    let replay = setup.contract.try_set_price_impact_cap(&setup.admin, &100);
+    assert_eq!(replay, Err(Ok(SoroswapError::OperationNotScheduled)));
+}
+
+#[test]
+fn cancelled_operation_is_rejected() {
+    let setup = timelocked();
+    let op_hash = cap_op_hash(&setup, 100);
+    setup.contract.schedule(&setup.admin, &op_hash, &(1_000 + DELAY));
+    setup.contract.cancel(&setup.admin, &op_hash);
+
+    setup.e.ledger().set_timestamp(1_000 + DELAY);
+    let result = setup.contract.try_set_price_impact_cap(&setup.admin, &100);
+    assert_eq!(result, Err(Ok(SoroswapError::OperationNotScheduled)));
+}
+
+#[test]
+fn schedule_must_respect_the_delay() {
+    let setup = timelocked();
+
+    let result = setup.contract.try_schedule(&setup.admin, &cap_op_hash(&setup, 100), &(1_000 + DELAY - 1));
+    assert_eq!(result, Err(Ok(SoroswapError::InvalidArgument)));
+}
+
+#[test]
+fn schedule_near_the_end_of_time_does_not_overflow() {
+    let setup = timelocked();
+    setup.e.ledger().set_timestamp(u64::MAX - 1);
+
+    let result = setup.contract.try_schedule(&setup.admin, &cap_op_hash(&setup, 100), &u64::MAX);
+    assert_eq!(result, Err(Ok(SoroswapError::InvalidArgument)));
+}
+
+#[test]
+fn delay_is_capped() {
+    let setup = Setup::new();
+
+    let result = setup.contract.try_set_timelock_delay(&setup.admin, &(MAX_TIMELOCK_DELAY + 1));
+    assert_eq!(result, Err(Ok(SoroswapError::InvalidArgument)));
+    assert_eq!(setup.contract.try_set_timelock_delay(&setup.admin, &u64::MAX), Err(Ok(SoroswapError::InvalidArgument)));
+
+    setup.contract.set_timelock_delay(&setup.admin, &MAX_TIMELOCK_DELAY);
+    assert_eq!(setup.contract.get_timelock_delay(), MAX_TIMELOCK_DELAY);
+}
@@ -0,0 +1,11 @@
+#![no_std]
+//! # Workshop Common
+//!
+//! Small helpers shared by the workshop contracts, so each crate doesn't carry its own copy.
+
+pub mod math;
+pub mod models;
+pub mod path;
+pub mod time;
+pub mod timelock;
+pub mod token;
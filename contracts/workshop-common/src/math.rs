@@ -0,0 +1,116 @@
+use soroban_sdk::{Env, I256};
+
+/// Failure of `mul_div`, converted into each contract's own error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MathError {
+    /// The denominator is zero
+    DivisionByZero,
+    /// The result doesn't fit in an `i128`
+    Overflow,
+}
+
+/// Rounding direction for `mul_div`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rounding {
+    /// Truncate toward zero
+    Down,
+    /// Round away from zero when there is a remainder
+    Up,
+}
+
+/// Computes `a * b / denominator` without intermediate overflow
+///
+/// The product is computed in 256 bits, so `a * b` may exceed `i128::MAX` as long as the final
+/// quotient fits in an `i128`. Use this for all bps math (e.g. `amount * bps / 10_000`).
+///
+/// Each contract converts `MathError` into its own error with a `From` impl, so `?` reports
+/// e.g. `DivisionByZero` as `InvalidArgument` and `Overflow` as `ArithmeticError`.
+pub fn mul_div(e: &Env, a: i128, b: i128, denominator: i128, rounding: Rounding) -> Result<i128, MathError> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+
+    let product = I256::from_i128(e, a).mul(&I256::from_i128(e, b));
+    let denominator_256 = I256::from_i128(e, denominator);
+    let quotient = product.div(&denominator_256);
+
+    let mut result = quotient.to_i128().ok_or(MathError::Overflow)?;
+
+    if rounding == Rounding::Up {
+        let remainder = product.sub(&quotient.mul(&denominator_256));
+        if remainder != I256::from_i128(e, 0) {
+            // The exact result is non-negative when the signs of the operands cancel out
+            let negative = (a < 0) ^ (b < 0) ^ (denominator < 0);
+            result = if negative {
+                result.checked_sub(1)
+            } else {
+                result.checked_add(1)
+            }
+            .ok_or(MathError::Overflow)?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::Env;
+
+    use super::{mul_div, MathError, Rounding};
+
+    fn mul_div_both(e: &Env, a: i128, b: i128, denominator: i128) -> (Result<i128, MathError>, Result<i128, MathError>) {
+        (mul_div(e, a, b, denominator, Rounding::Down), mul_div(e, a, b, denominator, Rounding::Up))
+    }
+
+    #[test]
+    fn rounds_in_the_requested_direction() {
+        let e = Env::default();
+
+        assert_eq!(mul_div_both(&e, 1_000, 30, 10_000), (Ok(3), Ok(3)));
+        assert_eq!(mul_div_both(&e, 1_001, 30, 10_000), (Ok(3), Ok(4)));
+        assert_eq!(mul_div_both(&e, 1, 1, 3), (Ok(0), Ok(1)));
+        assert_eq!(mul_div_both(&e, 0, 7, 3), (Ok(0), Ok(0)));
+    }
+
+    #[test]
+    fn negative_operands_round_away_from_zero() {
+        let e = Env::default();
+
+        assert_eq!(mul_div_both(&e, -7, 1, 2), (Ok(-3), Ok(-4)));
+        assert_eq!(mul_div_both(&e, 7, -1, 2), (Ok(-3), Ok(-4)));
+        assert_eq!(mul_div_both(&e, 7, 1, -2), (Ok(-3), Ok(-4)));
+        // The signs cancel out: the result is positive
+        assert_eq!(mul_div_both(&e, -7, -1, 2), (Ok(3), Ok(4)));
+        assert_eq!(mul_div_both(&e, -7, 1, -2), (Ok(3), Ok(4)));
+    }
+
+    #[test]
+    fn zero_denominator_is_rejected() {
+        let e = Env::default();
+
+        assert_eq!(mul_div_both(&e, 1, 1, 0), (Err(MathError::DivisionByZero), Err(MathError::DivisionByZero)));
+    }
+
+    #[test]
+    fn intermediate_product_may_exceed_i128() {
+        let e = Env::default();
+
+        assert_eq!(mul_div_both(&e, i128::MAX, i128::MAX, i128::MAX), (Ok(i128::MAX), Ok(i128::MAX)));
+        assert_eq!(mul_div_both(&e, i128::MAX, 10_000, 10_000), (Ok(i128::MAX), Ok(i128::MAX)));
+        assert_eq!(mul_div_both(&e, i128::MIN, 10_000, 10_000), (Ok(i128::MIN), Ok(i128::MIN)));
+        assert_eq!(mul_div_both(&e, i128::MAX, 3, 4), (Ok(i128::MAX / 4 * 3 + 2), Ok(i128::MAX / 4 * 3 + 3)));
+    }
+
+    #[test]
+    fn overflowing_result_is_rejected() {
+        let e = Env::default();
+
+        assert_eq!(mul_div_both(&e, i128::MAX, 2, 1), (Err(MathError::Overflow), Err(MathError::Overflow)));
+        assert_eq!(mul_div_both(&e, i128::MIN, -1, 1), (Err(MathError::Overflow), Err(MathError::Overflow)));
+
+        // (2^64 - 1) * (2^64 + 1) / 2 = i128::MAX remainder 1: only rounding up overflows
+        let (a, b) = (u64::MAX as i128, u64::MAX as i128 + 2);
+        assert_eq!(mul_div_both(&e, a, b, 2), (Ok(i128::MAX), Err(MathError::Overflow)));
+    }
+}
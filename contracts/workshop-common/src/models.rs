@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Outcome of a swap, returned by the swap entry points and emitted as `SwapExecuted`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SwapReceipt {
+    /// Token sold
+    pub token_in: Address,
+    /// Token purchased
+    pub token_out: Address,
+    /// Amount of `token_in` sold
+    pub amount_in: i128,
+    /// Amount of `token_out` received
+    pub amount_out: i128,
+    /// Number of tokens in the route (2 for a direct swap)
+    pub path_len: u32,
+}
+
+impl SwapReceipt {
+    /// Receipt of swapping `amount_in` along `path` for `amount_out`
+    pub fn new(path: &Vec<Address>, amount_in: i128, amount_out: i128) -> Self {
+        SwapReceipt {
+            token_in: path.first_unchecked(),
+            token_out: path.last_unchecked(),
+            amount_in,
+            amount_out,
+            path_len: path.len(),
+        }
+    }
+}
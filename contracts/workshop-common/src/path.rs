@@ -0,0 +1,237 @@
+use soroban_sdk::{contracttype, vec, Address, Env, Vec};
+
+/// Default maximum number of tokens allowed in a multi-hop swap path (admin-configurable)
+///
+/// Every hop is an extra pair call, so long paths can exhaust the budget or be used for griefing
+pub const DEFAULT_MAX_PATH_LEN: u32 = 4;
+
+/// Maximum number of candidate routes quoted by `select_best` (e.g. `best_path_swap`)
+pub const MAX_CANDIDATE_PATHS: u32 = 4;
+
+/// A validated Soroswap swap route
+///
+/// Can only be built through `SwapPath::new` or `SwapPath::direct`, so holding a `SwapPath`
+/// guarantees the route has at least one hop, respects the path length cap it was validated
+/// against and never swaps a token into itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SwapPath(Vec<Address>);
+
+impl SwapPath {
+    /// Validates a raw route
+    ///
+    /// Rejects (with `err`, each contract's `InvalidArgument`):
+    /// - an empty path
+    /// - a single token (no hop)
+    /// - more than `max_path_len` tokens
+    /// - the same token twice in a row (a token can't be swapped into itself)
+    pub fn new<E: Copy>(path: Vec<Address>, max_path_len: u32, err: E) -> Result<Self, E> {
+        if path.is_empty() {
+            return Err(err);
+        }
+        if path.len() < 2 || path.len() > max_path_len {
+            return Err(err);
+        }
+        for i in 1..path.len() {
+            if path.get_unchecked(i - 1) == path.get_unchecked(i) {
+                return Err(err);
+            }
+        }
+        Ok(SwapPath(path))
+    }
+
+    /// Builds a single-hop route: `token_in -> token_out`
+    ///
+    /// A single hop is always within the path length cap.
+    pub fn direct<E: Copy>(e: &Env, token_in: &Address, token_out: &Address, err: E) -> Result<Self, E> {
+        Self::new(vec![e, token_in.clone(), token_out.clone()], 2, err)
+    }
+
+    /// The raw route, as expected by the Soroswap Router
+    pub fn as_vec(&self) -> &Vec<Address> {
+        &self.0
+    }
+
+    /// The token sold (first token of the route)
+    pub fn token_in(&self) -> Address {
+        self.0.first_unchecked()
+    }
+
+    /// The token purchased (last token of the route)
+    pub fn token_out(&self) -> Address {
+        self.0.last_unchecked()
+    }
+}
+
+/// Quotes every candidate route and returns the one with the highest output
+///
+/// Each candidate must be a valid `SwapPath` from `token_in` to `token_out`, otherwise the
+/// whole selection fails with `invalid`. `quote` returns the output of a route, or `None` when
+/// the router can't quote it (e.g. a missing pair); such candidates are skipped, and
+/// `no_route` is returned if none is left.
+///
+/// ## Returns:
+/// `(index of the chosen candidate, chosen path)`
+pub fn select_best<E: Copy>(
+    candidate_paths: Vec<Vec<Address>>,
+    token_in: &Address,
+    token_out: &Address,
+    max_path_len: u32,
+    quote: impl Fn(&SwapPath) -> Option<i128>,
+    invalid: E,
+    no_route: E,
+) -> Result<(u32, SwapPath), E> {
+    if candidate_paths.is_empty() || candidate_paths.len() > MAX_CANDIDATE_PATHS {
+        return Err(invalid);
+    }
+
+    let mut best: Option<(u32, SwapPath, i128)> = None;
+    for (index, candidate) in candidate_paths.iter().enumerate() {
+        let path = SwapPath::new(candidate, max_path_len, invalid)?;
+        if path.token_in() != *token_in || path.token_out() != *token_out {
+            return Err(invalid);
+        }
+
+        if let Some(amount_out) = quote(&path) {
+            if best.as_ref().is_none_or(|(_, _, best_out)| amount_out > *best_out) {
+                best = Some((index as u32, path, amount_out));
+            }
+        }
+    }
+
+    best.map(|(index, path, _)| (index, path)).ok_or(no_route)
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+    use super::{select_best, SwapPath, DEFAULT_MAX_PATH_LEN, MAX_CANDIDATE_PATHS};
+
+    const INVALID: &str = "invalid";
+    const NO_ROUTE: &str = "no route";
+
+    /// Reference rule: at least one hop, at most `max_path_len` tokens, no token swapped into itself
+    fn is_well_formed(path: &Vec<Address>, max_path_len: u32) -> bool {
+        if path.len() < 2 || path.len() > max_path_len {
+            return false;
+        }
+        (1..path.len()).all(|i| path.get_unchecked(i - 1) != path.get_unchecked(i))
+    }
+
+    /// Deterministic xorshift generator, so a failing case can be replayed without extra dependencies
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as u32
+        }
+    }
+
+    #[test]
+    fn new_accepts_only_well_formed_paths() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+
+        // A small token pool makes repeated neighbours frequent
+        let tokens = vec![&e, Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..500 {
+            let max_path_len = 2 + rng.below(DEFAULT_MAX_PATH_LEN);
+            let mut path = Vec::new(&e);
+            for _ in 0..rng.below(max_path_len + 3) {
+                path.push_back(tokens.get_unchecked(rng.below(tokens.len())));
+            }
+
+            match SwapPath::new(path.clone(), max_path_len, INVALID) {
+                Ok(swap_path) => {
+                    assert!(is_well_formed(&path, max_path_len), "accepted {:?}", path);
+                    assert_eq!(swap_path.as_vec(), &path);
+                    assert_eq!(swap_path.token_in(), path.first_unchecked());
+                    assert_eq!(swap_path.token_out(), path.last_unchecked());
+                }
+                Err(err) => {
+                    assert!(!is_well_formed(&path, max_path_len), "rejected {:?}", path);
+                    assert_eq!(err, INVALID);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new_rejects_paths_over_the_cap() {
+        let e = Env::default();
+        let mut path = Vec::new(&e);
+        for _ in 0..DEFAULT_MAX_PATH_LEN {
+            path.push_back(Address::generate(&e));
+        }
+        assert!(SwapPath::new(path.clone(), DEFAULT_MAX_PATH_LEN, INVALID).is_ok());
+
+        path.push_back(Address::generate(&e));
+        assert_eq!(SwapPath::new(path.clone(), DEFAULT_MAX_PATH_LEN, INVALID), Err(INVALID));
+        assert!(SwapPath::new(path, DEFAULT_MAX_PATH_LEN + 1, INVALID).is_ok());
+    }
+
+    #[test]
+    fn direct_builds_a_single_hop() {
+        let e = Env::default();
+        let token_a = Address::generate(&e);
+        let token_b = Address::generate(&e);
+
+        let path = SwapPath::direct(&e, &token_a, &token_b, INVALID).unwrap();
+        assert_eq!(path.as_vec(), &vec![&e, token_a.clone(), token_b]);
+        assert_eq!(SwapPath::direct(&e, &token_a, &token_a, INVALID), Err(INVALID));
+    }
+
+    #[test]
+    fn select_best_picks_the_highest_quote() {
+        let e = Env::default();
+        let (token_a, token_b, token_c) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+        let direct = vec![&e, token_a.clone(), token_b.clone()];
+        let via_c = vec![&e, token_a.clone(), token_c.clone(), token_b.clone()];
+
+        // The two-hop route quotes higher, an unquotable route is skipped
+        let quote = |path: &SwapPath| match path.as_vec().len() {
+            2 => Some(100),
+            _ => Some(150),
+        };
+        let (index, path) = select_best(vec![&e, direct.clone(), via_c.clone()], &token_a, &token_b, DEFAULT_MAX_PATH_LEN, quote, INVALID, NO_ROUTE).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(path.as_vec(), &via_c);
+
+        let only_direct = |path: &SwapPath| (path.as_vec().len() == 2).then_some(100);
+        let (index, _) = select_best(vec![&e, via_c.clone(), direct.clone()], &token_a, &token_b, DEFAULT_MAX_PATH_LEN, only_direct, INVALID, NO_ROUTE).unwrap();
+        assert_eq!(index, 1);
+
+        // Ties keep the first candidate
+        let (index, _) = select_best(vec![&e, direct.clone(), direct.clone()], &token_a, &token_b, DEFAULT_MAX_PATH_LEN, |_: &SwapPath| Some(1), INVALID, NO_ROUTE).unwrap();
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    fn select_best_rejects_bad_candidates() {
+        let e = Env::default();
+        let (token_a, token_b, token_c) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+        let direct = vec![&e, token_a.clone(), token_b.clone()];
+        let quote = |_: &SwapPath| Some(1);
+
+        let select = |candidates: Vec<Vec<Address>>| select_best(candidates, &token_a, &token_b, DEFAULT_MAX_PATH_LEN, quote, INVALID, NO_ROUTE);
+        assert_eq!(select(Vec::new(&e)).err(), Some(INVALID));
+        // Wrong endpoints or a malformed route fail the whole selection
+        assert_eq!(select(vec![&e, direct.clone(), vec![&e, token_a.clone(), token_c.clone()]]).err(), Some(INVALID));
+        assert_eq!(select(vec![&e, vec![&e, token_a.clone(), token_a.clone(), token_b.clone()]]).err(), Some(INVALID));
+
+        let mut too_many = Vec::new(&e);
+        for _ in 0..=MAX_CANDIDATE_PATHS {
+            too_many.push_back(direct.clone());
+        }
+        assert_eq!(select(too_many).err(), Some(INVALID));
+
+        let none = select_best(vec![&e, direct], &token_a, &token_b, DEFAULT_MAX_PATH_LEN, |_: &SwapPath| None, INVALID, NO_ROUTE);
+        assert_eq!(none.err(), Some(NO_ROUTE));
+    }
+}
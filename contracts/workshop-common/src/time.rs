@@ -0,0 +1,60 @@
+use soroban_sdk::Env;
+
+/// Seconds added to the current ledger timestamp when the caller does not provide a deadline
+pub const DEFAULT_DEADLINE_WINDOW: u64 = 300;
+
+/// Resolves an optional deadline, defaulting to `now + DEFAULT_DEADLINE_WINDOW`
+///
+/// Safer than `u64::MAX`: a transaction stuck in the mempool can't be executed hours later
+pub fn resolve_deadline(e: &Env, deadline: Option<u64>) -> u64 {
+    deadline.unwrap_or_else(|| e.ledger().timestamp().saturating_add(DEFAULT_DEADLINE_WINDOW))
+}
+
+/// Returns `err` if the ledger timestamp is past `deadline`
+///
+/// A deadline equal to the current timestamp is still valid, matching the Soroswap router.
+/// Generic over the error type so each contract can report its own `DeadlineExpired`.
+pub fn ensure_not_expired<E>(e: &Env, deadline: u64, err: E) -> Result<(), E> {
+    if e.ledger().timestamp() > deadline {
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::{testutils::Ledger, Env};
+
+    use super::{ensure_not_expired, resolve_deadline, DEFAULT_DEADLINE_WINDOW};
+
+    #[test]
+    fn explicit_deadline_is_kept() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1_000);
+
+        assert_eq!(resolve_deadline(&e, Some(1_234)), 1_234);
+        // Even one already in the past: expiry is checked separately
+        assert_eq!(resolve_deadline(&e, Some(10)), 10);
+    }
+
+    #[test]
+    fn missing_deadline_defaults_to_window() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1_000);
+
+        assert_eq!(resolve_deadline(&e, None), 1_000 + DEFAULT_DEADLINE_WINDOW);
+
+        e.ledger().set_timestamp(u64::MAX - 1);
+        assert_eq!(resolve_deadline(&e, None), u64::MAX);
+    }
+
+    #[test]
+    fn deadline_is_inclusive() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1_000);
+
+        assert_eq!(ensure_not_expired(&e, 1_001, "expired"), Ok(()));
+        assert_eq!(ensure_not_expired(&e, 1_000, "expired"), Ok(()));
+        assert_eq!(ensure_not_expired(&e, 999, "expired"), Err("expired"));
+    }
+}
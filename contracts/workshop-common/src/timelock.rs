@@ -0,0 +1,106 @@
+use soroban_sdk::{contracttype, vec, xdr::ToXdr, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+/// Storage keys of the timelock
+///
+/// Same variant names as the `DataKey` entries the contracts used before the timelock was
+/// shared, so existing schedules and delays are still found after an upgrade.
+#[derive(Clone)]
+#[contracttype]
+enum TimelockKey {
+    TimelockDelay,
+    ScheduledOp(BytesN<32>),
+}
+
+/// Longest timelock delay the admin can set (30 days)
+///
+/// Keeps `now + delay` far from overflowing and an admin from locking themselves out for good.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+const DAY_IN_LEDGERS: u32 = 17280;
+const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Failure of a timelock operation, converted into each contract's own error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimelockError {
+    /// The operation was not scheduled
+    NotScheduled,
+    /// The operation was scheduled but its `execute_after` has not been reached
+    NotExpired,
+    /// `execute_after` is sooner than now plus the delay
+    TooEarly,
+    /// The delay is over `MAX_TIMELOCK_DELAY`
+    DelayTooLong,
+}
+
+/// Hash identifying an admin operation: `sha256(xdr([fn_name, args...]))`
+///
+/// `args` are the operation's arguments in declaration order, without the `admin` address.
+pub fn op_hash(e: &Env, fn_name: Symbol, args: Vec<Val>) -> BytesN<32> {
+    let mut op: Vec<Val> = vec![e, fn_name.into_val(e)];
+    op.append(&args);
+    e.crypto().sha256(&op.to_xdr(e)).to_bytes()
+}
+
+/// The timelock delay in seconds (0 = disabled)
+pub fn get_delay(e: &Env) -> u64 {
+    e.storage().instance().get(&TimelockKey::TimelockDelay).unwrap_or(0)
+}
+
+/// Sets the timelock delay, consuming the schedule of `set_timelock_delay(delay)` first
+///
+/// Changing the delay is itself timelocked once a delay is in place, so a compromised admin
+/// can't disable it instantly. The delay is capped at `MAX_TIMELOCK_DELAY`.
+pub fn set_delay(e: &Env, delay: u64) -> Result<(), TimelockError> {
+    if delay > MAX_TIMELOCK_DELAY {
+        return Err(TimelockError::DelayTooLong);
+    }
+    check_timelock(e, "set_timelock_delay", vec![e, delay.into_val(e)])?;
+    e.storage().instance().set(&TimelockKey::TimelockDelay, &delay);
+    Ok(())
+}
+
+/// Schedules the operation `op_hash` to run from `execute_after` on
+///
+/// `execute_after` must leave at least the current delay from now.
+pub fn schedule(e: &Env, op_hash: &BytesN<32>, execute_after: u64) -> Result<(), TimelockError> {
+    // An overflowing earliest time can't be reached by any `execute_after` either
+    let earliest = e.ledger().timestamp().checked_add(get_delay(e)).ok_or(TimelockError::TooEarly)?;
+    if execute_after < earliest {
+        return Err(TimelockError::TooEarly);
+    }
+
+    let key = TimelockKey::ScheduledOp(op_hash.clone());
+    e.storage().persistent().set(&key, &execute_after);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    Ok(())
+}
+
+/// Removes the schedule of `op_hash`, if any
+pub fn cancel(e: &Env, op_hash: &BytesN<32>) {
+    e.storage().persistent().remove(&TimelockKey::ScheduledOp(op_hash.clone()));
+}
+
+/// Consumes the schedule of an admin operation, if the timelock is enabled
+///
+/// With a zero delay the timelock is disabled and every operation runs immediately.
+/// Otherwise the operation must have been scheduled and its `execute_after` reached;
+/// the schedule is removed so it can't be replayed.
+pub fn check_timelock(e: &Env, fn_name: &str, args: Vec<Val>) -> Result<(), TimelockError> {
+    if get_delay(e) == 0 {
+        return Ok(());
+    }
+
+    let op_hash = op_hash(e, Symbol::new(e, fn_name), args);
+    let execute_after: u64 = e
+        .storage()
+        .persistent()
+        .get(&TimelockKey::ScheduledOp(op_hash.clone()))
+        .ok_or(TimelockError::NotScheduled)?;
+    if e.ledger().timestamp() < execute_after {
+        return Err(TimelockError::NotExpired);
+    }
+
+    cancel(e, &op_hash);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{token, Address, Env};
+
+/// Transfers `amount` of `token` and verifies the recipient's balance grew by exactly `amount`
+///
+/// Guards against non-standard tokens (fee-on-transfer, rebasing) that silently under-deliver.
+/// Generic over the error type so each contract can report its own `TransferMismatch`.
+pub fn safe_transfer<E>(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128, err: E) -> Result<(), E> {
+    let token_client = token::Client::new(e, token);
+    let balance_before = token_client.balance(to);
+    token_client.transfer(from, to, &amount);
+    if from != to && token_client.balance(to) - balance_before != amount {
+        return Err(err);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+    use workshop_mocks::{MockFeeToken, MockFeeTokenClient};
+
+    use super::safe_transfer;
+
+    #[test]
+    fn standard_token_transfers() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let (from, to) = (Address::generate(&e), Address::generate(&e));
+        let token = e.register_stellar_asset_contract_v2(Address::generate(&e)).address();
+        token::StellarAssetClient::new(&e, &token).mint(&from, &1_000);
+
+        assert_eq!(safe_transfer(&e, &token, &from, &to, 400, "mismatch"), Ok(()));
+        assert_eq!(token::Client::new(&e, &token).balance(&to), 400);
+        // A self-transfer leaves the balance unchanged and is not a mismatch
+        assert_eq!(safe_transfer(&e, &token, &from, &from, 100, "mismatch"), Ok(()));
+    }
+
+    #[test]
+    fn fee_on_transfer_token_is_rejected() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let (from, to) = (Address::generate(&e), Address::generate(&e));
+        // 1% fee on every transfer
+        let fee_token = MockFeeTokenClient::new(&e, &e.register(MockFeeToken, (100_u32,)));
+        fee_token.mint(&from, &1_000);
+
+        assert_eq!(safe_transfer(&e, &fee_token.address, &from, &to, 500, "mismatch"), Err("mismatch"));
+    }
+}
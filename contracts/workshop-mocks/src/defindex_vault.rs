@@ -0,0 +1,2 @@
+// Only the vault's types are used: the mock must speak the same XDR as the real vault
+soroban_sdk::contractimport!(file = "../wasms/defindex_vault.wasm");
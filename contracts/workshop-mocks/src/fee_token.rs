@@ -0,0 +1,52 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    FeeBps,
+    Balance(Address),
+}
+
+/// Mock fee-on-transfer token: every transfer delivers `amount` minus `fee_bps` of it
+///
+/// The minimal token interface (`balance`, `transfer`, `decimals`) plus an unguarded `mint`.
+#[contract]
+pub struct MockFeeToken;
+
+fn set_balance(e: &Env, id: Address, amount: i128) {
+    e.storage().instance().set(&DataKey::Balance(id), &amount);
+}
+
+#[contractimpl]
+impl MockFeeToken {
+    pub fn __constructor(e: Env, fee_bps: u32) {
+        e.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+    }
+
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        let balance = Self::balance(e.clone(), to.clone());
+        set_balance(&e, to, balance + amount);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        e.storage().instance().get(&DataKey::Balance(id)).unwrap_or(0)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let fee_bps: u32 = e.storage().instance().get(&DataKey::FeeBps).unwrap();
+        let fee = amount * fee_bps as i128 / 10_000;
+
+        let from_balance = Self::balance(e.clone(), from.clone());
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        set_balance(&e, from, from_balance - amount);
+        let to_balance = Self::balance(e.clone(), to.clone());
+        set_balance(&e, to, to_balance + amount - fee);
+    }
+
+    pub fn decimals(_e: Env) -> u32 {
+        7
+    }
+}
@@ -0,0 +1,29 @@
+#![no_std]
+//! # Workshop Mocks
+//!
+//! Configurable mock contracts for testing the workshop contracts without deploying
+//! Soroswap or DeFindex:
+//! - `MockRouter`: Soroswap router with fixed per-pair rates (pulls `token_in` from `to` like
+//!   the real router, pays out from its own balance)
+//! - `MockVault`: single- or multi-asset DeFindex vault with pro-rata shares and a single
+//!   strategy
+//! - `MockStaking`: staking contract that pulls and records vault shares
+//! - `MockFeeToken`: token that skims a fee on every transfer
+//!
+//! Every mock can be told to fail, so error paths can be exercised too. Register them with
+//! `e.register(MockRouter, ())` etc. and fund them with test tokens as needed.
+//!
+//! There is no Blend pool mock: none of the workshop contracts talk to Blend yet.
+
+// The generated client exceeds clippy's argument limit in test builds
+#[allow(clippy::too_many_arguments)]
+mod defindex_vault;
+mod fee_token;
+mod router;
+mod staking;
+mod vault;
+
+pub use fee_token::{MockFeeToken, MockFeeTokenClient};
+pub use router::{MockRouter, MockRouterClient, MockRouterError};
+pub use staking::{MockStaking, MockStakingClient};
+pub use vault::{MockVault, MockVaultClient, MockVaultError};
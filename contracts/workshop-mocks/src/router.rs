@@ -0,0 +1,196 @@
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, vec, Address, Env, Vec};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MockRouterError {
+    /// Swaps were disabled with `set_fail`
+    SwapDisabled = 1,
+    /// No rate was configured for a hop of the path
+    PairNotFound = 2,
+    /// The output is below `amount_out_min`
+    InsufficientOutputAmount = 3,
+    /// The deadline has passed
+    DeadlineExpired = 4,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Rate(Address, Address),
+    Reserves(Address, Address),
+    Pair(Address, Address),
+    Fail,
+    Leak,
+    SwapCalls,
+}
+
+/// Reserves seeded by `set_rate`, scaled so the spot price matches the rate
+const DEFAULT_RESERVE_SCALE: i128 = 1_000_000_000;
+
+/// Mock Soroswap router
+///
+/// Each pair swaps at a fixed rate set with `set_rate`, so outputs are deterministic. Like the
+/// real router, `swap_exact_tokens_for_tokens` requires `to`'s auth and pulls `token_in` from
+/// `to` into the pair (the router itself unless `set_pair` says otherwise). The output is paid
+/// from the router's own balance, so tests must fund it.
+#[contract]
+pub struct MockRouter;
+
+fn amounts_out(e: &Env, amount_in: i128, path: &Vec<Address>) -> Result<Vec<i128>, MockRouterError> {
+    let mut amounts = vec![e, amount_in];
+    let mut amount = amount_in;
+    for i in 0..path.len() - 1 {
+        let (num, den): (i128, i128) = e
+            .storage()
+            .instance()
+            .get(&DataKey::Rate(path.get_unchecked(i), path.get_unchecked(i + 1)))
+            .ok_or(MockRouterError::PairNotFound)?;
+        amount = amount * num / den;
+        amounts.push_back(amount);
+    }
+    Ok(amounts)
+}
+
+fn pair_for(e: &Env, token_a: &Address, token_b: &Address) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::Pair(token_a.clone(), token_b.clone()))
+        .unwrap_or(e.current_contract_address())
+}
+
+#[contractimpl]
+impl MockRouter {
+    /// Swap `token_a -> token_b` at `num / den` (and `token_b -> token_a` at the inverse)
+    ///
+    /// Also seeds reserves matching the rate, i.e. a pool deep enough for no price impact.
+    pub fn set_rate(e: Env, token_a: Address, token_b: Address, num: i128, den: i128) {
+        let storage = e.storage().instance();
+        storage.set(&DataKey::Rate(token_a.clone(), token_b.clone()), &(num, den));
+        storage.set(&DataKey::Rate(token_b.clone(), token_a.clone()), &(den, num));
+        Self::set_reserves(e, token_a, token_b, den * DEFAULT_RESERVE_SCALE, num * DEFAULT_RESERVE_SCALE);
+    }
+
+    /// Overrides the reserves reported by `get_reserves` (e.g. for a shallow pool)
+    pub fn set_reserves(e: Env, token_a: Address, token_b: Address, reserve_a: i128, reserve_b: i128) {
+        let storage = e.storage().instance();
+        storage.set(&DataKey::Reserves(token_a.clone(), token_b.clone()), &(reserve_a, reserve_b));
+        storage.set(&DataKey::Reserves(token_b, token_a), &(reserve_b, reserve_a));
+    }
+
+    /// Overrides the pair address returned by `router_pair_for` (and receiving `token_in`)
+    pub fn set_pair(e: Env, token_a: Address, token_b: Address, pair: Address) {
+        let storage = e.storage().instance();
+        storage.set(&DataKey::Pair(token_a.clone(), token_b.clone()), &pair);
+        storage.set(&DataKey::Pair(token_b, token_a), &pair);
+    }
+
+    /// Makes every swap fail with `SwapDisabled`
+    pub fn set_fail(e: Env, fail: bool) {
+        e.storage().instance().set(&DataKey::Fail, &fail);
+    }
+
+    /// After every swap, also send `amount` of the output token to `to`
+    ///
+    /// Simulates a misbehaving router that leaves tokens behind.
+    pub fn set_leak(e: Env, to: Address, amount: i128) {
+        e.storage().instance().set(&DataKey::Leak, &(to, amount));
+    }
+
+    /// Number of swaps executed so far
+    pub fn swap_calls(e: Env) -> u32 {
+        e.storage().instance().get(&DataKey::SwapCalls).unwrap_or(0)
+    }
+
+    pub fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, MockRouterError> {
+        if e.storage().instance().get(&DataKey::Fail).unwrap_or(false) {
+            return Err(MockRouterError::SwapDisabled);
+        }
+        to.require_auth();
+        if e.ledger().timestamp() > deadline {
+            return Err(MockRouterError::DeadlineExpired);
+        }
+
+        let amounts = amounts_out(&e, amount_in, &path)?;
+        let amount_out = amounts.last_unchecked();
+        if amount_out < amount_out_min {
+            return Err(MockRouterError::InsufficientOutputAmount);
+        }
+
+        let token_in = path.first_unchecked();
+        let token_out = path.last_unchecked();
+        let pair = pair_for(&e, &token_in, &path.get_unchecked(1));
+        token::Client::new(&e, &token_in).transfer(&to, &pair, &amount_in);
+
+        let token_out_client = token::Client::new(&e, &token_out);
+        token_out_client.transfer(&e.current_contract_address(), &to, &amount_out);
+        if let Some((leak_to, leak_amount)) = e.storage().instance().get::<_, (Address, i128)>(&DataKey::Leak) {
+            token_out_client.transfer(&e.current_contract_address(), &leak_to, &leak_amount);
+        }
+
+        e.storage().instance().set(&DataKey::SwapCalls, &(Self::swap_calls(e.clone()) + 1));
+        Ok(amounts)
+    }
+
+    /// Burns `liquidity` LP tokens (the pair's token) from `to` and pays out `liquidity` of
+    /// `token_a` plus its value in `token_b`
+    #[allow(clippy::too_many_arguments)]
+    pub fn remove_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), MockRouterError> {
+        to.require_auth();
+        if e.ledger().timestamp() > deadline {
+            return Err(MockRouterError::DeadlineExpired);
+        }
+
+        let pair = pair_for(&e, &token_a, &token_b);
+        token::Client::new(&e, &pair).transfer(&to, &pair, &liquidity);
+
+        let amount_a = liquidity;
+        let amount_b = amounts_out(&e, liquidity, &vec![&e, token_a.clone(), token_b.clone()])?.last_unchecked();
+        if amount_a < amount_a_min || amount_b < amount_b_min {
+            return Err(MockRouterError::InsufficientOutputAmount);
+        }
+
+        token::Client::new(&e, &token_a).transfer(&e.current_contract_address(), &to, &amount_a);
+        token::Client::new(&e, &token_b).transfer(&e.current_contract_address(), &to, &amount_b);
+        Ok((amount_a, amount_b))
+    }
+
+    pub fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, MockRouterError> {
+        amounts_out(&e, amount_in, &path)
+    }
+
+    pub fn get_factory(e: Env) -> Address {
+        e.current_contract_address()
+    }
+
+    pub fn get_reserves(e: Env, _factory: Address, token_a: Address, token_b: Address) -> Result<(i128, i128), MockRouterError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Reserves(token_a, token_b))
+            .ok_or(MockRouterError::PairNotFound)
+    }
+
+    pub fn router_quote(_e: Env, amount_a: i128, reserve_a: i128, reserve_b: i128) -> i128 {
+        amount_a * reserve_b / reserve_a
+    }
+
+    pub fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Address {
+        pair_for(&e, &token_a, &token_b)
+    }
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Vault,
+    Staked(Address),
+}
+
+/// Mock staking contract: `stake` pulls vault shares from `from` and records them
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn __constructor(e: Env, vault: Address) {
+        e.storage().instance().set(&DataKey::Vault, &vault);
+    }
+
+    pub fn stake(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let vault: Address = e.storage().instance().get(&DataKey::Vault).unwrap();
+        token::Client::new(&e, &vault).transfer(&from, e.current_contract_address(), &amount);
+
+        let staked = Self::staked(e.clone(), from.clone());
+        e.storage().instance().set(&DataKey::Staked(from), &(staked + amount));
+    }
+
+    /// Vault shares staked by `from`
+    pub fn staked(e: Env, from: Address) -> i128 {
+        e.storage().instance().get(&DataKey::Staked(from)).unwrap_or(0)
+    }
+}
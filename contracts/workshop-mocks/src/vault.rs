@@ -0,0 +1,287 @@
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, vec, Address, Env, String, Vec};
+
+use crate::defindex_vault::{
+    AssetInvestmentAllocation, AssetStrategySet, CurrentAssetInvestmentAllocation, Instruction, Strategy,
+    StrategyAllocation,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MockVaultError {
+    /// Deposits were disabled with `set_fail_deposit`
+    DepositDisabled = 1,
+    /// Investing was disabled with `set_fail_invest`
+    InvestDisabled = 2,
+    /// The caller is not the rebalance manager
+    Unauthorized = 3,
+    /// The strategy was paused with `set_strategy_paused`
+    StrategyPaused = 4,
+    /// Only `Instruction::Invest` is supported
+    UnsupportedInstruction = 5,
+    /// Wrong number of amounts, zero amounts or shares over the balance
+    InvalidArgument = 6,
+    /// An amount is below its minimum
+    InsufficientOutputAmount = 7,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Assets,
+    Strategy,
+    StrategyPaused,
+    RebalanceManager,
+    FailDeposit,
+    FailInvest,
+    Invested(Address),
+    InvestCalls,
+    Claimed(Address),
+    Balance(Address),
+    TotalSupply,
+}
+
+/// Mock DeFindex vault
+///
+/// Shares are minted pro-rata to the first asset's total managed funds (1:1 on the first
+/// deposit), so sending underlying to the vault raises the share price. Every asset shares one
+/// strategy; investing moves the funds to the strategy address and tracks them as invested.
+/// Like the real vault, `deposit` requires `from`'s auth and pulls the assets from `from`.
+/// `deposit` result: amounts deposited, shares minted and the (always empty) investments
+type DepositResult = (Vec<i128>, i128, Option<Vec<Option<AssetInvestmentAllocation>>>);
+
+#[contract]
+pub struct MockVault;
+
+fn get<V: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(e: &Env, key: &DataKey) -> Option<V> {
+    e.storage().instance().get(key)
+}
+
+fn assets(e: &Env) -> Vec<Address> {
+    get(e, &DataKey::Assets).unwrap()
+}
+
+fn invested(e: &Env, asset: &Address) -> i128 {
+    get(e, &DataKey::Invested(asset.clone())).unwrap_or(0)
+}
+
+fn idle(e: &Env, asset: &Address) -> i128 {
+    token::Client::new(e, asset).balance(&e.current_contract_address())
+}
+
+fn total_managed(e: &Env, asset: &Address) -> i128 {
+    idle(e, asset) + invested(e, asset)
+}
+
+fn set_balance(e: &Env, id: Address, amount: i128) {
+    e.storage().instance().set(&DataKey::Balance(id), &amount);
+}
+
+fn invest(e: &Env, asset: &Address, amount: i128) -> Result<(), MockVaultError> {
+    if get(e, &DataKey::StrategyPaused).unwrap_or(false) {
+        return Err(MockVaultError::StrategyPaused);
+    }
+    let strategy: Address = get(e, &DataKey::Strategy).unwrap();
+    token::Client::new(e, asset).transfer(&e.current_contract_address(), &strategy, &amount);
+    e.storage().instance().set(&DataKey::Invested(asset.clone()), &(invested(e, asset) + amount));
+    Ok(())
+}
+
+#[contractimpl]
+impl MockVault {
+    pub fn __constructor(e: Env, assets: Vec<Address>, strategy: Address) {
+        e.storage().instance().set(&DataKey::Assets, &assets);
+        e.storage().instance().set(&DataKey::Strategy, &strategy);
+    }
+
+    /// Makes every deposit fail with `DepositDisabled`
+    pub fn set_fail_deposit(e: Env, fail: bool) {
+        e.storage().instance().set(&DataKey::FailDeposit, &fail);
+    }
+
+    /// Makes every deposit with `invest = true` and every rebalance fail with `InvestDisabled`
+    pub fn set_fail_invest(e: Env, fail: bool) {
+        e.storage().instance().set(&DataKey::FailInvest, &fail);
+    }
+
+    pub fn set_strategy_paused(e: Env, paused: bool) {
+        e.storage().instance().set(&DataKey::StrategyPaused, &paused);
+    }
+
+    pub fn set_rebalance_manager(e: Env, new_rebalance_manager: Address) {
+        e.storage().instance().set(&DataKey::RebalanceManager, &new_rebalance_manager);
+    }
+
+    /// Number of successful `rebalance` calls
+    pub fn invest_calls(e: Env) -> u32 {
+        get(&e, &DataKey::InvestCalls).unwrap_or(0)
+    }
+
+    /// Optional reward claim hook, recording how many times `from` claimed
+    pub fn claim(e: Env, from: Address) {
+        let claimed = Self::claimed(e.clone(), from.clone());
+        e.storage().instance().set(&DataKey::Claimed(from), &(claimed + 1));
+    }
+
+    pub fn claimed(e: Env, from: Address) -> u32 {
+        get(&e, &DataKey::Claimed(from)).unwrap_or(0)
+    }
+
+    pub fn deposit(
+        e: Env,
+        amounts_desired: Vec<i128>,
+        amounts_min: Vec<i128>,
+        from: Address,
+        invest: bool,
+    ) -> Result<DepositResult, MockVaultError> {
+        from.require_auth();
+        if get(&e, &DataKey::FailDeposit).unwrap_or(false) {
+            return Err(MockVaultError::DepositDisabled);
+        }
+        if invest && get(&e, &DataKey::FailInvest).unwrap_or(false) {
+            return Err(MockVaultError::InvestDisabled);
+        }
+        let assets = assets(&e);
+        if amounts_desired.len() != assets.len() || amounts_min.len() != assets.len() {
+            return Err(MockVaultError::InvalidArgument);
+        }
+        let amount = amounts_desired.get_unchecked(0);
+        if amount <= 0 {
+            return Err(MockVaultError::InvalidArgument);
+        }
+        for (desired, min) in amounts_desired.iter().zip(amounts_min.iter()) {
+            if desired < min {
+                return Err(MockVaultError::InsufficientOutputAmount);
+            }
+        }
+
+        let first_asset = assets.get_unchecked(0);
+        let total_supply = Self::total_supply(e.clone());
+        let total_managed = total_managed(&e, &first_asset);
+        let shares = if total_supply == 0 || total_managed == 0 {
+            amount
+        } else {
+            amount * total_supply / total_managed
+        };
+
+        for (asset, desired) in assets.iter().zip(amounts_desired.iter()) {
+            token::Client::new(&e, &asset).transfer(&from, e.current_contract_address(), &desired);
+            if invest {
+                self::invest(&e, &asset, desired)?;
+            }
+        }
+
+        e.storage().instance().set(&DataKey::TotalSupply, &(total_supply + shares));
+        set_balance(&e, from.clone(), Self::balance(e.clone(), from) + shares);
+        Ok((amounts_desired, shares, None))
+    }
+
+    /// Burns `withdraw_shares` from `from` and pays out its pro-rata share of every idle asset
+    pub fn withdraw(e: Env, withdraw_shares: i128, min_amounts_out: Vec<i128>, from: Address) -> Result<Vec<i128>, MockVaultError> {
+        from.require_auth();
+        let assets = assets(&e);
+        let balance = Self::balance(e.clone(), from.clone());
+        if withdraw_shares <= 0 || withdraw_shares > balance || min_amounts_out.len() != assets.len() {
+            return Err(MockVaultError::InvalidArgument);
+        }
+
+        let amounts = Self::get_asset_amounts_per_shares(e.clone(), withdraw_shares);
+        for ((asset, amount), min) in assets.iter().zip(amounts.iter()).zip(min_amounts_out.iter()) {
+            if amount < min {
+                return Err(MockVaultError::InsufficientOutputAmount);
+            }
+            token::Client::new(&e, &asset).transfer(&e.current_contract_address(), &from, &amount);
+        }
+
+        let total_supply = Self::total_supply(e.clone());
+        e.storage().instance().set(&DataKey::TotalSupply, &(total_supply - withdraw_shares));
+        set_balance(&e, from, balance - withdraw_shares);
+        Ok(amounts)
+    }
+
+    pub fn get_assets(e: Env) -> Vec<AssetStrategySet> {
+        let strategy = Strategy {
+            address: get(&e, &DataKey::Strategy).unwrap(),
+            name: String::from_str(&e, "mock"),
+            paused: get(&e, &DataKey::StrategyPaused).unwrap_or(false),
+        };
+        let mut sets = vec![&e];
+        for asset in assets(&e).iter() {
+            sets.push_back(AssetStrategySet { address: asset, strategies: vec![&e, strategy.clone()] });
+        }
+        sets
+    }
+
+    pub fn fetch_total_managed_funds(e: Env) -> Vec<CurrentAssetInvestmentAllocation> {
+        let strategy_address: Address = get(&e, &DataKey::Strategy).unwrap();
+        let paused = get(&e, &DataKey::StrategyPaused).unwrap_or(false);
+        let mut allocations = vec![&e];
+        for asset in assets(&e).iter() {
+            let idle_amount = idle(&e, &asset);
+            let invested_amount = invested(&e, &asset);
+            allocations.push_back(CurrentAssetInvestmentAllocation {
+                asset,
+                idle_amount,
+                invested_amount,
+                strategy_allocations: vec![
+                    &e,
+                    StrategyAllocation { amount: invested_amount, paused, strategy_address: strategy_address.clone() },
+                ],
+                total_amount: idle_amount + invested_amount,
+            });
+        }
+        allocations
+    }
+
+    pub fn get_asset_amounts_per_shares(e: Env, vault_shares: i128) -> Vec<i128> {
+        let total_supply = Self::total_supply(e.clone());
+        let mut amounts = vec![&e];
+        for asset in assets(&e).iter() {
+            let amount = if total_supply == 0 { 0 } else { vault_shares * total_managed(&e, &asset) / total_supply };
+            amounts.push_back(amount);
+        }
+        amounts
+    }
+
+    pub fn rebalance(e: Env, caller: Address, instructions: Vec<Instruction>) -> Result<(), MockVaultError> {
+        caller.require_auth();
+        if get::<Address>(&e, &DataKey::RebalanceManager) != Some(caller) {
+            return Err(MockVaultError::Unauthorized);
+        }
+        if get(&e, &DataKey::FailInvest).unwrap_or(false) {
+            return Err(MockVaultError::InvestDisabled);
+        }
+        for instruction in instructions.iter() {
+            let Instruction::Invest(_, amount) = instruction else {
+                return Err(MockVaultError::UnsupportedInstruction);
+            };
+            // Single strategy: the amount comes out of the first asset
+            invest(&e, &assets(&e).get_unchecked(0), amount)?;
+        }
+        e.storage().instance().set(&DataKey::InvestCalls, &(Self::invest_calls(e.clone()) + 1));
+        Ok(())
+    }
+
+    pub fn total_supply(e: Env) -> i128 {
+        get(&e, &DataKey::TotalSupply).unwrap_or(0)
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        get(&e, &DataKey::Balance(id)).unwrap_or(0)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) -> Result<(), MockVaultError> {
+        from.require_auth();
+        let from_balance = Self::balance(e.clone(), from.clone());
+        if amount < 0 || amount > from_balance {
+            return Err(MockVaultError::InvalidArgument);
+        }
+        set_balance(&e, from, from_balance - amount);
+        set_balance(&e, to.clone(), Self::balance(e.clone(), to) + amount);
+        Ok(())
+    }
+
+    pub fn decimals(_e: Env) -> u32 {
+        7
+    }
+}
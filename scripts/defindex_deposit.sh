@@ -1 +1 @@
-stellar contract invoke --id CDVPCLH7ISXWEAH4CQA7WWDZ3YKROPRGQGQXD4SINZT55L5LH2YMADVK --source me --network testnet -- deposit --caller GCAXSAYRQTFEYXXWYY5QV7TBZSZSSWLX2VGGIEAMU6UGNZNIW6NQXS37 --token_in CDWEFYYHMGEZEFC5TBUDXM3IJJ7K7W5BDGE765UIYQEV4JFWDOLSTOEK --amount 100000000
\ No newline at end of file
+stellar contract invoke --id CDVPCLH7ISXWEAH4CQA7WWDZ3YKROPRGQGQXD4SINZT55L5LH2YMADVK --source me --network testnet -- deposit --caller GCAXSAYRQTFEYXXWYY5QV7TBZSZSSWLX2VGGIEAMU6UGNZNIW6NQXS37 --token_in CDWEFYYHMGEZEFC5TBUDXM3IJJ7K7W5BDGE765UIYQEV4JFWDOLSTOEK --amount 100000000 --min_shares 0 --max_amount_in 100000000
\ No newline at end of file
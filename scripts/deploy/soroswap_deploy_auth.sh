@@ -1 +1 @@
-stellar contract deploy --source-account me --wasm ./target/wasm32v1-none/release/soroswap_auth.wasm  -- --router_address "CCMAPXWVZD4USEKDWRYS7DA4Y3D7E2SDMGBFJUCEXTC7VN6CUBGWPFUS"
\ No newline at end of file
+stellar contract deploy --source-account me --wasm ./target/wasm32v1-none/release/soroswap_auth.wasm  -- --admin GCAXSAYRQTFEYXXWYY5QV7TBZSZSSWLX2VGGIEAMU6UGNZNIW6NQXS37 --router_address "CCMAPXWVZD4USEKDWRYS7DA4Y3D7E2SDMGBFJUCEXTC7VN6CUBGWPFUS"
\ No newline at end of file